@@ -0,0 +1,42 @@
+//! Answers of parts as written in TOML files of expected answers.
+//!
+//! Answers are written in a table with optional `part1` and `part2` keys, as
+//! strings or integers:
+//!
+//! ```toml
+//! part1 = 3
+//! part2 = "6"
+//! ```
+//!
+//! This module is available with the `answers` feature.
+
+use serde::Deserialize;
+
+/// An expected answer as written in a file, either a string or an integer.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(untagged)]
+pub enum AnswerValue {
+    /// An answer written as a string.
+    Text(String),
+    /// An answer written as an integer.
+    Integer(i64),
+}
+
+impl From<AnswerValue> for String {
+    fn from(value: AnswerValue) -> Self {
+        match value {
+            AnswerValue::Text(text) => text,
+            AnswerValue::Integer(integer) => integer.to_string(),
+        }
+    }
+}
+
+/// A table of expected answers of parts as written in a file.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct AnswerTable {
+    /// The expected answer of part 1, if known.
+    pub part1: Option<AnswerValue>,
+    /// The expected answer of part 2, if known.
+    pub part2: Option<AnswerValue>,
+}
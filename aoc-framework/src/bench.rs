@@ -0,0 +1,214 @@
+//! Benchmarking of solutions with repeated runs.
+//!
+//! Solutions are benchmarked by parsing input and running parts many times,
+//! recording a duration sample for each run. Parsed input is parsed once for
+//! running parts, so the parts are measured independently from parsing.
+
+use std::convert::Infallible;
+use std::hint::black_box;
+use std::time::Duration;
+
+use crate::{PartSelection, SolutionPart, measure_time};
+
+/// Options for benchmarking a solution.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BenchOptions {
+    /// The number of runs to discard before sampling, to warm up caches.
+    pub warmup: u32,
+    /// The number of runs to sample.
+    pub runs: u32,
+    /// The parts to benchmark.
+    pub parts: PartSelection,
+}
+
+impl Default for BenchOptions {
+    fn default() -> Self {
+        Self {
+            warmup: 3,
+            runs: 100,
+            parts: PartSelection::All,
+        }
+    }
+}
+
+/// A phase of a solution that is benchmarked.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BenchPhase {
+    /// Parsing input, for solutions with distinct parsing.
+    Parse,
+    /// Running a part.
+    Part(SolutionPart),
+}
+
+impl BenchPhase {
+    /// Get a default name for a phase.
+    #[must_use]
+    pub fn default_name(self) -> &'static str {
+        match self {
+            Self::Parse => "Parse",
+            Self::Part(part) => part.default_name(),
+        }
+    }
+}
+
+/// Statistics of duration samples.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BenchStats {
+    /// The number of samples.
+    pub runs: usize,
+    /// The minimum duration.
+    pub min: Duration,
+    /// The median duration.
+    pub median: Duration,
+    /// The mean duration.
+    pub mean: Duration,
+    /// The 95th percentile duration, by nearest rank.
+    pub p95: Duration,
+    /// The population standard deviation of durations.
+    pub stddev: Duration,
+}
+
+impl BenchStats {
+    /// Calculate statistics of duration samples, if there are any samples.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::Duration;
+    /// use aoc_framework::bench::BenchStats;
+    ///
+    /// let samples = [4, 1, 3, 2].map(Duration::from_micros);
+    /// let stats = BenchStats::from_samples(&samples).expect("has samples");
+    /// assert_eq!(stats.min, Duration::from_micros(1));
+    /// assert_eq!(stats.median, Duration::from_nanos(2_500));
+    /// assert_eq!(stats.p95, Duration::from_micros(4));
+    ///
+    /// assert_eq!(BenchStats::from_samples(&[]), None);
+    /// ```
+    #[must_use]
+    pub fn from_samples(samples: &[Duration]) -> Option<Self> {
+        let mut sorted = samples.to_vec();
+        sorted.sort_unstable();
+        let runs = sorted.len();
+        let min = *sorted.first()?;
+
+        let median = if runs.is_multiple_of(2) {
+            (sorted[runs / 2 - 1] + sorted[runs / 2]) / 2
+        } else {
+            sorted[runs / 2]
+        };
+
+        // nearest rank is the ceiling of 95% of the count
+        let p95 = sorted[(runs * 95).div_ceil(100) - 1];
+
+        let total: Duration = sorted.iter().sum();
+        #[expect(
+            clippy::cast_possible_truncation,
+            reason = "sample counts are far below u32::MAX"
+        )]
+        let mean = total / runs as u32;
+
+        #[expect(
+            clippy::cast_precision_loss,
+            reason = "variance is approximate at nanosecond resolution"
+        )]
+        let variance = sorted
+            .iter()
+            .map(|sample| {
+                let delta = sample.as_secs_f64() - mean.as_secs_f64();
+                delta * delta
+            })
+            .sum::<f64>()
+            / runs as f64;
+        let stddev = Duration::from_secs_f64(variance.sqrt());
+
+        Some(Self {
+            runs,
+            min,
+            median,
+            mean,
+            p95,
+            stddev,
+        })
+    }
+}
+
+/// The statistics of a benchmarked phase.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PhaseStats {
+    /// The phase that was benchmarked.
+    pub phase: BenchPhase,
+    /// The statistics of the phase's samples.
+    pub stats: BenchStats,
+}
+
+/// A report of benchmarking a solution's phases.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct BenchReport {
+    /// The statistics of each phase, in the order they were run.
+    pub phases: Vec<PhaseStats>,
+}
+
+impl BenchReport {
+    /// Add the statistics of a phase's samples to the report.
+    ///
+    /// If there are no samples, the phase is not added.
+    pub fn push(&mut self, phase: BenchPhase, samples: &[Duration]) {
+        if let Some(stats) = BenchStats::from_samples(samples) {
+            self.phases.push(PhaseStats { phase, stats });
+        }
+    }
+}
+
+/// Sample the durations of running a fallible function repeatedly.
+///
+/// The function is run `options.warmup` times before sampling
+/// `options.runs` times. Results are passed through
+/// [`black_box`][std::hint::black_box] so runs can't be optimized away.
+///
+/// # Errors
+///
+/// If any run returns an error, sampling stops and the error is returned.
+pub fn try_sample<T, E>(
+    options: &BenchOptions,
+    mut function: impl FnMut() -> Result<T, E>,
+) -> Result<Vec<Duration>, E> {
+    for _ in 0..options.warmup {
+        black_box(function()?);
+    }
+
+    let mut samples = Vec::with_capacity(options.runs as usize);
+    for _ in 0..options.runs {
+        let (result, duration) = measure_time!(function());
+        black_box(result?);
+        samples.push(duration);
+    }
+    Ok(samples)
+}
+
+/// Sample the durations of running a function repeatedly.
+///
+/// See [`try_sample`] for how runs are sampled.
+///
+/// # Examples
+///
+/// ```
+/// use aoc_framework::bench::{BenchOptions, sample};
+///
+/// let options = BenchOptions {
+///     warmup: 2,
+///     runs: 5,
+///     ..BenchOptions::default()
+/// };
+/// let mut count = 0;
+/// let samples = sample(&options, || count += 1);
+/// assert_eq!(samples.len(), 5);
+/// assert_eq!(count, 7);
+/// ```
+pub fn sample<T>(
+    options: &BenchOptions,
+    mut function: impl FnMut() -> T,
+) -> Vec<Duration> {
+    let Ok(samples) = try_sample(options, || Ok::<_, Infallible>(function()));
+    samples
+}
@@ -1,9 +1,13 @@
-//! Error and result types for parsing inputs from Advent of Code.
+//! Error and result types for parsing inputs from Advent of Code and running
+//! solutions.
 
+use std::any::Any;
 use std::num::{ParseFloatError, ParseIntError};
 
 use thiserror::Error;
 
+use crate::SolutionPart;
+
 /// A return type for results related to input parsing.
 pub type ParseResult<T> = core::result::Result<T, ParseError>;
 
@@ -46,7 +50,30 @@ pub enum ParseError {
     InvalidLine {
         /// The line number. This should be one-indexed (the first line is 1).
         line: usize,
-        source: Box<ParseError>,
+        source: Box<Self>,
+    },
+
+    /// A span of columns in a line caused a parsing error.
+    ///
+    /// When wrapped in another invalid column error, the span is relative to
+    /// the outer span, for errors parsing a part of a line on its own.
+    #[error("failure parsing column {column}")]
+    InvalidColumn {
+        /// The column number of the start of the span. This should be
+        /// one-indexed (the first character is column 1).
+        column: usize,
+        /// The number of characters in the span.
+        width: usize,
+        source: Box<Self>,
+    },
+
+    /// A field of a delimited line caused a parsing error.
+    #[error("failure parsing field {field}")]
+    InvalidField {
+        /// The field number. This should be one-indexed (the first field is
+        /// 1).
+        field: usize,
+        source: Box<Self>,
     },
 
     /// Expected a delimiter while parsing. Contains the delimiter for display.
@@ -76,6 +103,21 @@ pub enum ParseError {
     /// An invalid character was parsed.
     #[error("invalid character: {0:?}")]
     ParseChar(char),
+
+    /// Input was parsed by a pattern into values that don't match its
+    /// fields, like a text field parsed as an integer. Contains a description
+    /// for display.
+    #[error("pattern mismatch: {0}")]
+    PatternMismatch(String),
+
+    /// A parsed index refers past the end of what it indexes.
+    #[error("index {index} out of range for length {len}")]
+    IndexOutOfRange {
+        /// The index parsed.
+        index: usize,
+        /// The length of what the index refers into.
+        len: usize,
+    },
 }
 
 impl ParseError {
@@ -96,6 +138,137 @@ impl ParseError {
         }
     }
 
+    /// Create an invalid column error from a zero-based character index, span
+    /// width, and source error.
+    #[must_use]
+    pub fn invalid_column_from_zero_index(
+        index: usize,
+        width: usize,
+        source: Self,
+    ) -> Self {
+        Self::invalid_column_from_one_based(
+            index.saturating_add(1),
+            width,
+            source,
+        )
+    }
+
+    /// Create an invalid column error from a one-based column number, span
+    /// width, and source error.
+    #[must_use]
+    pub fn invalid_column_from_one_based(
+        column: usize,
+        width: usize,
+        source: Self,
+    ) -> Self {
+        Self::InvalidColumn {
+            column,
+            width,
+            source: Box::new(source),
+        }
+    }
+
+    /// Create an invalid field error from a zero-based field index and source
+    /// error.
+    #[must_use]
+    pub fn invalid_field_from_zero_index(index: usize, source: Self) -> Self {
+        Self::invalid_field_from_one_based(index.saturating_add(1), source)
+    }
+
+    /// Create an invalid field error from a one-based field number and source
+    /// error.
+    #[must_use]
+    pub fn invalid_field_from_one_based(field: usize, source: Self) -> Self {
+        Self::InvalidField {
+            field,
+            source: Box::new(source),
+        }
+    }
+
+    /// Get the location in input that caused this error, if known.
+    ///
+    /// The line is taken from the outermost [`ParseError::InvalidLine`], and
+    /// the columns from the innermost [`ParseError::InvalidColumn`] it wraps,
+    /// offset by any outer invalid column errors.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use aoc_framework::{ParseError, SourceSpan};
+    ///
+    /// let error = ParseError::invalid_line_from_zero_index(
+    ///     4,
+    ///     ParseError::invalid_column_from_zero_index(
+    ///         1,
+    ///         1,
+    ///         ParseError::ParseChar('b'),
+    ///     ),
+    /// );
+    /// assert_eq!(
+    ///     error.location(),
+    ///     Some(SourceSpan {
+    ///         line: 5,
+    ///         column: Some(2),
+    ///         width: 1,
+    ///     })
+    /// );
+    /// assert_eq!(ParseError::EmptyInput.location(), None);
+    ///
+    /// // the third character of a span starting at column 5
+    /// let error = ParseError::invalid_line_from_one_based(
+    ///     1,
+    ///     ParseError::invalid_column_from_one_based(
+    ///         5,
+    ///         4,
+    ///         ParseError::invalid_column_from_one_based(
+    ///             3,
+    ///             1,
+    ///             ParseError::ParseChar('x'),
+    ///         ),
+    ///     ),
+    /// );
+    /// assert_eq!(
+    ///     error.location(),
+    ///     Some(SourceSpan {
+    ///         line: 1,
+    ///         column: Some(7),
+    ///         width: 1,
+    ///     })
+    /// );
+    /// ```
+    #[must_use]
+    pub fn location(&self) -> Option<SourceSpan> {
+        let Self::InvalidLine { line, source } = self else {
+            return None;
+        };
+
+        let mut span = SourceSpan {
+            line: *line,
+            column: None,
+            width: 0,
+        };
+        let mut current = source.as_ref();
+        loop {
+            match current {
+                Self::InvalidLine { source, .. }
+                | Self::InvalidField { source, .. } => current = source,
+                Self::InvalidColumn {
+                    column,
+                    width,
+                    source,
+                } => {
+                    // nested spans are relative to the enclosing span
+                    let start = span.column.map_or(0, |outer| outer - 1);
+                    span.column = Some(start + column);
+                    span.width = *width;
+                    current = source;
+                }
+                _ => break,
+            }
+        }
+        Some(span)
+    }
+
     /// Create a parse int error from a string slice and source error.
     #[must_use]
     pub fn parse_int_from_str(string: &str, source: ParseIntError) -> Self {
@@ -114,3 +287,92 @@ impl ParseError {
         }
     }
 }
+
+/// A location in input that caused a parse error.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SourceSpan {
+    /// The line number. This is one-indexed (the first line is 1).
+    pub line: usize,
+    /// The column number of the start of the span, if known. This is
+    /// one-indexed (the first character is column 1).
+    pub column: Option<usize>,
+    /// The number of characters in the span, zero if the column is unknown.
+    pub width: usize,
+}
+
+/// A return type for results of solving a part.
+pub type SolveResult<T> = core::result::Result<T, SolveError>;
+
+/// An error solving a part from parsed input.
+///
+/// Input may parse correctly but still have no answer, so fallible parts can
+/// report that instead of panicking.
+#[derive(Error, Debug)]
+pub enum SolveError {
+    /// Parsing input within a part failed, for parts that parse their own
+    /// input. This is reported as a parse error when running the part.
+    #[error(transparent)]
+    Parse(#[from] ParseError),
+
+    /// No answer exists for the input. Contains a description for display.
+    #[error("no solution found: {0}")]
+    NoSolution(String),
+
+    /// Calculating the answer overflowed. Contains a description for display.
+    #[error("arithmetic overflow: {0}")]
+    Overflow(String),
+}
+
+/// A return type for results of running a solution.
+pub type RunResult<T> = core::result::Result<T, RunError>;
+
+/// An error running a solution.
+///
+/// Solutions may panic on unexpected conditions, so panics while parsing
+/// input or running a part are caught and reported as errors.
+#[derive(Error, Debug)]
+pub enum RunError {
+    /// Parsing input failed.
+    #[error("solution failed to parse input")]
+    Parse(#[from] ParseError),
+
+    /// Solving a part failed.
+    #[error("{} failed to solve", .part.default_name())]
+    Solve {
+        /// The part that failed.
+        part: SolutionPart,
+        source: SolveError,
+    },
+
+    /// A part was selected to run that the solution doesn't implement.
+    #[error("{} is not implemented", .0.default_name())]
+    PartNotImplemented(SolutionPart),
+
+    /// Parsing input panicked. Contains the panic message.
+    #[error("parsing input panicked: {0}")]
+    ParsePanic(String),
+
+    /// Running a part panicked.
+    #[error("{} panicked: {message}", .part.default_name())]
+    PartPanic {
+        /// The part that panicked.
+        part: SolutionPart,
+        /// The panic message.
+        message: String,
+    },
+}
+
+impl RunError {
+    /// Get the message of a caught panic's payload.
+    ///
+    /// Panics with a formatted or literal message have a string payload.
+    /// Other payloads have no message to display.
+    #[must_use]
+    pub fn panic_message(payload: &(dyn Any + Send)) -> String {
+        payload
+            .downcast_ref::<&str>()
+            .map(|message| (*message).to_string())
+            .or_else(|| payload.downcast_ref::<String>().cloned())
+            .unwrap_or_else(|| String::from("unknown panic payload"))
+    }
+}
@@ -0,0 +1,93 @@
+//! Normalization of puzzle input to canonical text.
+
+use std::borrow::Cow;
+use std::fmt;
+use std::ops::Deref;
+
+/// The byte order mark some editors write at the start of UTF-8 files.
+const BOM: char = '\u{feff}';
+
+/// Puzzle input normalized to canonical text, so solutions don't need to
+/// handle how an input file was saved.
+///
+/// Normalizing input:
+///
+/// - strips a leading UTF-8 byte order mark,
+/// - converts CRLF line endings to LF,
+/// - removes trailing blank (empty or whitespace only) lines, and
+/// - ends non-empty input with a single line ending.
+///
+/// Whitespace within lines is kept, including trailing spaces of the last
+/// line that isn't blank, as some puzzles align columns with spaces.
+///
+/// [`impl_runnable_solution!`][crate::impl_runnable_solution] normalizes
+/// input before running solutions, so parsing and parts only see canonical
+/// text.
+///
+/// # Examples
+///
+/// ```
+/// use aoc_framework::Input;
+///
+/// let input = Input::new("\u{feff}1 2\r\n3 4  \r\n\r\n  \r\n");
+/// assert_eq!(input.as_str(), "1 2\n3 4  \n");
+///
+/// // canonical input is borrowed as is
+/// assert_eq!(&*Input::new("1 2\n3 4\n"), "1 2\n3 4\n");
+/// assert_eq!(Input::new("1 2\n3 4").as_str(), "1 2\n3 4\n");
+/// assert_eq!(Input::new(" \n\n").as_str(), "");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Input<'a>(Cow<'a, str>);
+
+impl<'a> Input<'a> {
+    /// Normalize raw input to canonical text.
+    #[must_use]
+    pub fn new(raw: &'a str) -> Self {
+        let text = raw.strip_prefix(BOM).unwrap_or(raw);
+
+        // cut after the line ending of the last line that isn't blank
+        let end = text.rfind(|c: char| !c.is_whitespace()).map_or(0, |last| {
+            text[last..]
+                .find('\n')
+                .map_or(text.len(), |newline| last + newline + 1)
+        });
+        let text = &text[..end];
+
+        let mut text = if text.contains('\r') {
+            Cow::Owned(text.replace("\r\n", "\n"))
+        } else {
+            Cow::Borrowed(text)
+        };
+        if !text.is_empty() && !text.ends_with('\n') {
+            text.to_mut().push('\n');
+        }
+        Self(text)
+    }
+
+    /// Get the normalized text.
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl Deref for Input<'_> {
+    type Target = str;
+
+    fn deref(&self) -> &Self::Target {
+        self.as_str()
+    }
+}
+
+impl AsRef<str> for Input<'_> {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for Input<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
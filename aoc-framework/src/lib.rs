@@ -4,16 +4,35 @@
 //!
 //! - [`RunnableSolution`]: trait for solutions that can be run dynamically.
 //! - [`SolutionName`]: trait to provide a name for a solution.
+//! - [`SolutionPart`] and [`PartSelection`]: parts of a solution, and
+//!   selections of parts to run.
 //! - [`Part1`], [`Part2`]: traits for solutions that implement part 1 and part
 //!   2 respectively.
 //! - [`ParsedPart1`], [`ParsedPart2`]: traits for solutions that implement
 //!   part 1 and part 2 respectively, with separate parsing of input.
+//! - [`TryPart1`], [`TryPart2`], [`TryParsedPart1`], [`TryParsedPart2`]:
+//!   traits like [`Part1`], [`Part2`], [`ParsedPart1`], and [`ParsedPart2`],
+//!   with parts that can fail to solve. The infallible traits are implemented
+//!   on top of these.
+//! - [`Input`]: puzzle input normalized to canonical text before solutions
+//!   see it.
 //! - [`ParseError`] and [`ParseResult`]: structured parsing errors returned by
 //!   parsers.
+//! - [`SolveError`] and [`SolveResult`]: errors returned by fallible parts
+//!   when no answer can be found.
+//! - [`RunError`] and [`RunResult`]: errors running solutions, including
+//!   panics caught while parsing or running parts.
 //! - [`OutputHandler`]: trait used by runner to receive output events.
+//! - [`Registry`] and [`SolutionEntry`]: a static list of runnable solutions
+//!   looked up by day.
+//! - [`bench`]: repeated runs of solutions with duration statistics.
 //! - [`measure_time!`] macro: helper to measure duration of an expression.
 //! - [`impl_runnable_solution!`] macro: helper to implement
 //!   [`RunnableSolution`] for solution types.
+//! - `answers` module (with the `answers` feature): expected answers as
+//!   written in TOML files.
+//! - `testing` module and `test_examples!` macro (with the `testing`
+//!   feature): checks of solutions against example data files.
 //!
 //! # Quick Start
 //!
@@ -24,6 +43,9 @@
 //!   needed).
 //! - With distinct parsing step: implement [`ParsedPart1`] (and
 //!   [`ParsedPart2`] if needed).
+//! - With parts that can fail to solve: implement [`TryPart1`] (and
+//!   [`TryPart2`] if needed), or with a distinct parsing step,
+//!   [`TryParsedPart1`] (and [`TryParsedPart2`] if needed).
 //!
 //! Then, use the [`impl_runnable_solution!`] macro to implement
 //! [`RunnableSolution`].
@@ -169,9 +191,9 @@
 //! //   Part 2: 100 (completed in 0 seconds, 87654321 nanoseconds)
 //! ```
 //!
-//! In real code, parsing errors are propagated up to the runner, which won't
-//! call output methods if parsing fails; no error handling is needed in the
-//! output handler itself.
+//! In real code, parsing errors and panics are propagated up to the runner as
+//! a [`RunError`], which won't call further output methods after a failure;
+//! no error handling is needed in the output handler itself.
 
 #![warn(clippy::suspicious, clippy::complexity, clippy::perf, clippy::pedantic)]
 #![warn(
@@ -198,15 +220,30 @@
     clippy::unwrap_used
 )]
 
+#[cfg(feature = "answers")]
+pub mod answers;
+pub mod bench;
 pub mod error;
+pub mod input;
 pub mod macros;
 pub mod output;
+pub mod registry;
 pub mod solution;
+#[cfg(feature = "testing")]
+pub mod testing;
 
 // re-export commonly used items
-pub use error::{ParseError, ParseResult};
+pub use error::{
+    ParseError, ParseResult, RunError, RunResult, SolveError, SolveResult,
+    SourceSpan,
+};
+pub use input::Input;
 pub use output::OutputHandler;
-pub use solution::{ParsedPart1, ParsedPart2, Part1, Part2, SolutionName};
+pub use registry::{Registry, SolutionEntry};
+pub use solution::{
+    ParsedPart1, ParsedPart2, Part1, Part2, SolutionName, TryParsedPart1,
+    TryParsedPart2, TryPart1, TryPart2,
+};
 
 /// An enum to identify parts of a solution.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -226,6 +263,38 @@ impl SolutionPart {
     }
 }
 
+/// A selection of parts of a solution to run.
+///
+/// # Examples
+///
+/// ```
+/// use aoc_framework::{PartSelection, SolutionPart};
+///
+/// let selection = PartSelection::Only(SolutionPart::Part2);
+/// assert!(!selection.includes(SolutionPart::Part1));
+/// assert!(selection.includes(SolutionPart::Part2));
+/// assert!(PartSelection::All.includes(SolutionPart::Part1));
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum PartSelection {
+    /// Every implemented part.
+    #[default]
+    All,
+    /// Only the given part, if implemented.
+    Only(SolutionPart),
+}
+
+impl PartSelection {
+    /// Check if a part is selected.
+    #[must_use]
+    pub fn includes(self, part: SolutionPart) -> bool {
+        match self {
+            Self::All => true,
+            Self::Only(selected) => selected == part,
+        }
+    }
+}
+
 /// A trait for Advent of Code solutions that can be run.
 ///
 /// This trait is implemented for solution types via the
@@ -241,15 +310,51 @@ pub trait RunnableSolution {
     ///
     /// # Errors
     ///
-    /// If parsing input fails, a [`ParseError`] is returned.
+    /// If parsing input fails, a [`RunError::Parse`] is returned. If parsing
+    /// or running a part panics, the panic is caught and returned as a
+    /// [`RunError`].
+    fn run(
+        handler: &mut dyn OutputHandler,
+        input: &str,
+        timed: bool,
+    ) -> RunResult<()> {
+        Self::run_parts(handler, input, PartSelection::All, timed)
+    }
+
+    /// Run the solution, parsing input and running selected parts if
+    /// implemented.
     ///
-    /// # Panics
+    /// Input is parsed regardless of the parts selected.
     ///
-    /// A solution part's implementation may panic if unexpected conditions
-    /// occur, as Advent of Code problems generally expect correct inputs.
-    fn run(
+    /// See [`RunnableSolution::run`] for other arguments used.
+    ///
+    /// # Errors
+    ///
+    /// If a selected part is not implemented, a
+    /// [`RunError::PartNotImplemented`] is returned. If parsing input fails, a
+    /// [`RunError::Parse`] is returned. If parsing or running a part panics,
+    /// the panic is caught and returned as a [`RunError`].
+    fn run_parts(
         handler: &mut dyn OutputHandler,
         input: &str,
+        parts: PartSelection,
         timed: bool,
-    ) -> ParseResult<()>;
+    ) -> RunResult<()>;
+
+    /// Benchmark the solution, sampling durations of parsing input and
+    /// running parts repeatedly with the given options.
+    ///
+    /// Solutions with distinct parsing parse input once to run parts with.
+    /// Only parts selected by the options are benchmarked.
+    ///
+    /// # Errors
+    ///
+    /// If a selected part is not implemented, a
+    /// [`RunError::PartNotImplemented`] is returned. If parsing input fails, a
+    /// [`RunError::Parse`] is returned. If parsing or running a part panics,
+    /// the panic is caught and returned as a [`RunError`].
+    fn bench(
+        input: &str,
+        options: &bench::BenchOptions,
+    ) -> RunResult<bench::BenchReport>;
 }
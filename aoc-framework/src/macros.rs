@@ -35,10 +35,18 @@ macro_rules! measure_time {
 ///
 /// This macro takes the solution type and the trait it implements
 /// (e.g., [`Part1`][crate::Part1], [`Part2`][crate::Part2],
-/// [`ParsedPart1`][crate::ParsedPart1], [`ParsedPart2`][crate::ParsedPart2])
+/// [`ParsedPart1`][crate::ParsedPart1], [`ParsedPart2`][crate::ParsedPart2],
+/// or their fallible variants like [`TryPart1`][crate::TryPart1] and
+/// [`TryParsedPart2`][crate::TryParsedPart2])
 /// and generates the necessary implementation of
 /// [`RunnableSolution`][crate::RunnableSolution].
 ///
+/// Infallible traits run through their fallible variants, which they are
+/// implemented for.
+///
+/// The implementation normalizes input with [`Input`][crate::Input] before
+/// parsing and running parts.
+///
 /// # Examples
 ///
 /// Implementing for a solution that has both parts:
@@ -95,47 +103,98 @@ macro_rules! measure_time {
 #[macro_export]
 macro_rules! impl_runnable_solution {
     ($solution:ty => Part1) => {
-        impl $crate::RunnableSolution for $solution {
-            fn run(
-                handler: &mut dyn $crate::OutputHandler,
-                input: &str,
-                timed: bool,
-            ) -> $crate::ParseResult<()> {
-                <$solution as $crate::Part1>::run(handler, input, timed)
-            }
-        }
+        $crate::impl_runnable_solution!(@impl $solution, TryPart1);
     };
     ($solution:ty => Part2) => {
-        impl $crate::RunnableSolution for $solution {
-            fn run(
-                handler: &mut dyn $crate::OutputHandler,
-                input: &str,
-                timed: bool,
-            ) -> $crate::ParseResult<()> {
-                <$solution as $crate::Part2>::run(handler, input, timed)
-            }
-        }
+        $crate::impl_runnable_solution!(@impl $solution, TryPart2);
+    };
+    ($solution:ty => TryPart1) => {
+        $crate::impl_runnable_solution!(@impl $solution, TryPart1);
+    };
+    ($solution:ty => TryPart2) => {
+        $crate::impl_runnable_solution!(@impl $solution, TryPart2);
     };
     ($solution:ty => ParsedPart1) => {
-        impl $crate::RunnableSolution for $solution {
-            fn run(
-                handler: &mut dyn $crate::OutputHandler,
-                input: &str,
-                timed: bool,
-            ) -> $crate::ParseResult<()> {
-                <$solution as $crate::ParsedPart1>::run(handler, input, timed)
-            }
-        }
+        $crate::impl_runnable_solution!(@impl $solution, TryParsedPart1);
     };
     ($solution:ty => ParsedPart2) => {
+        $crate::impl_runnable_solution!(@impl $solution, TryParsedPart2);
+    };
+    ($solution:ty => TryParsedPart1) => {
+        $crate::impl_runnable_solution!(@impl $solution, TryParsedPart1);
+    };
+    ($solution:ty => TryParsedPart2) => {
+        $crate::impl_runnable_solution!(@impl $solution, TryParsedPart2);
+    };
+    (@impl $solution:ty, $part_trait:ident) => {
         impl $crate::RunnableSolution for $solution {
-            fn run(
+            fn run_parts(
                 handler: &mut dyn $crate::OutputHandler,
                 input: &str,
+                parts: $crate::PartSelection,
                 timed: bool,
-            ) -> $crate::ParseResult<()> {
-                <$solution as $crate::ParsedPart2>::run(handler, input, timed)
+            ) -> $crate::RunResult<()> {
+                let input = $crate::Input::new(input);
+                <$solution as $crate::$part_trait>::run_parts(
+                    handler,
+                    input.as_str(),
+                    parts,
+                    timed,
+                )
+            }
+
+            fn bench(
+                input: &str,
+                options: &$crate::bench::BenchOptions,
+            ) -> $crate::RunResult<$crate::bench::BenchReport> {
+                let input = $crate::Input::new(input);
+                <$solution as $crate::$part_trait>::bench(
+                    input.as_str(),
+                    options,
+                )
             }
         }
     };
 }
+
+/// Generate tests that check solutions against their example data files.
+///
+/// Each test is named for a directory under `tests/examples/` of the calling
+/// crate, and checks the solution type against every example case in it
+/// with [`check_examples`][crate::testing::check_examples]. Attributes like
+/// `#[ignore]` can be given before a test's name.
+///
+/// This macro is available with the `testing` feature.
+///
+/// # Examples
+///
+/// ```ignore
+/// use aoc_framework::test_examples;
+/// use solutions::{day01::Day01, day02::Day02};
+///
+/// // checks tests/examples/day01/ and tests/examples/day02/
+/// test_examples! {
+///     day01 => Day01,
+///     #[ignore = "still working on solution"] day02 => Day02,
+/// }
+/// ```
+#[cfg(feature = "testing")]
+#[macro_export]
+macro_rules! test_examples {
+    ($($(#[$meta:meta])* $name:ident => $solution:ty),* $(,)?) => {
+        $(
+            $(#[$meta])*
+            #[test]
+            fn $name() -> ::std::result::Result<
+                (),
+                $crate::testing::ExampleError,
+            > {
+                let dir = ::std::path::Path::new(env!("CARGO_MANIFEST_DIR"))
+                    .join("tests")
+                    .join("examples")
+                    .join(stringify!($name));
+                $crate::testing::check_examples::<$solution>(&dir)
+            }
+        )*
+    };
+}
@@ -0,0 +1,162 @@
+//! A registry of runnable solutions by day.
+
+use crate::bench::{BenchOptions, BenchReport};
+use crate::{
+    OutputHandler, PartSelection, RunResult, RunnableSolution, SolutionName,
+};
+
+/// A function that runs a solution.
+///
+/// See [`RunnableSolution::run_parts`] for arguments used.
+pub type SolutionRunner =
+    fn(&mut dyn OutputHandler, &str, PartSelection, bool) -> RunResult<()>;
+
+/// A function that benchmarks a solution.
+///
+/// See [`RunnableSolution::bench`] for arguments used.
+pub type SolutionBencher = fn(&str, &BenchOptions) -> RunResult<BenchReport>;
+
+/// An entry of a solution registered for a day.
+#[derive(Debug, Clone, Copy)]
+pub struct SolutionEntry {
+    /// The day the solution is for.
+    pub day: u8,
+    /// The solution's display name.
+    pub name: &'static str,
+    /// The function to run the solution.
+    pub runner: SolutionRunner,
+    /// The function to benchmark the solution.
+    pub bencher: SolutionBencher,
+}
+
+impl SolutionEntry {
+    /// Create an entry for a solution type on the given day.
+    ///
+    /// This is a `const fn` so entries can be listed in a static
+    /// [`Registry`].
+    #[must_use]
+    pub const fn new<S: RunnableSolution + SolutionName>(day: u8) -> Self {
+        Self {
+            day,
+            name: S::NAME,
+            runner: S::run_parts,
+            bencher: S::bench,
+        }
+    }
+
+    /// Run the entry's solution.
+    ///
+    /// See [`RunnableSolution::run`] for arguments used.
+    ///
+    /// # Errors
+    ///
+    /// If parsing input fails, a [`RunError::Parse`][crate::RunError::Parse]
+    /// is returned. If parsing or running a part panics, the panic is caught
+    /// and returned as a [`RunError`][crate::RunError].
+    pub fn run(
+        &self,
+        handler: &mut dyn OutputHandler,
+        input: &str,
+        timed: bool,
+    ) -> RunResult<()> {
+        self.run_parts(handler, input, PartSelection::All, timed)
+    }
+
+    /// Run selected parts of the entry's solution.
+    ///
+    /// See [`RunnableSolution::run_parts`] for arguments used.
+    ///
+    /// # Errors
+    ///
+    /// If parsing input fails, a [`RunError::Parse`][crate::RunError::Parse]
+    /// is returned. If parsing or running a part panics, the panic is caught
+    /// and returned as a [`RunError`][crate::RunError].
+    pub fn run_parts(
+        &self,
+        handler: &mut dyn OutputHandler,
+        input: &str,
+        parts: PartSelection,
+        timed: bool,
+    ) -> RunResult<()> {
+        (self.runner)(handler, input, parts, timed)
+    }
+
+    /// Benchmark the entry's solution.
+    ///
+    /// See [`RunnableSolution::bench`] for arguments used.
+    ///
+    /// # Errors
+    ///
+    /// If parsing input fails, a [`RunError::Parse`][crate::RunError::Parse]
+    /// is returned. If parsing or running a part panics, the panic is caught
+    /// and returned as a [`RunError`][crate::RunError].
+    pub fn bench(
+        &self,
+        input: &str,
+        options: &BenchOptions,
+    ) -> RunResult<BenchReport> {
+        (self.bencher)(input, options)
+    }
+}
+
+/// A static list of solutions, looked up by day.
+///
+/// # Examples
+///
+/// ```
+/// use aoc_framework::{
+///     ParseResult, Part1, Registry, SolutionEntry, SolutionName,
+///     impl_runnable_solution,
+/// };
+///
+/// struct MySolution;
+/// impl SolutionName for MySolution {
+///     const NAME: &'static str = "My Solution";
+/// }
+/// impl Part1 for MySolution {
+///     type Part1Output = usize;
+///     fn part1(input: &str) -> ParseResult<Self::Part1Output> {
+///         Ok(input.len())
+///     }
+/// }
+/// impl_runnable_solution!(MySolution => Part1);
+///
+/// static SOLUTIONS: Registry =
+///     Registry::new(&[SolutionEntry::new::<MySolution>(1)]);
+///
+/// assert_eq!(SOLUTIONS.get(1).map(|entry| entry.name), Some("My Solution"));
+/// assert!(SOLUTIONS.get(2).is_none());
+/// assert_eq!(SOLUTIONS.days().collect::<Vec<_>>(), vec![1]);
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct Registry {
+    /// The registered entries.
+    entries: &'static [SolutionEntry],
+}
+
+impl Registry {
+    /// Create a registry from a list of entries.
+    ///
+    /// Entries are expected to have unique days. If a day is repeated, the
+    /// first entry for the day is used.
+    #[must_use]
+    pub const fn new(entries: &'static [SolutionEntry]) -> Self {
+        Self { entries }
+    }
+
+    /// Get the entry for a day, if registered.
+    #[must_use]
+    pub fn get(&self, day: u8) -> Option<&'static SolutionEntry> {
+        self.entries.iter().find(|entry| entry.day == day)
+    }
+
+    /// Get an iterator over registered entries, in registration order.
+    pub fn entries(&self) -> impl Iterator<Item = &'static SolutionEntry> {
+        self.entries.iter()
+    }
+
+    /// Get an iterator over registered days, in registration order.
+    pub fn days(&self) -> impl Iterator<Item = u8> {
+        self.entries.iter().map(|entry| entry.day)
+    }
+}
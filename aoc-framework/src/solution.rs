@@ -1,8 +1,134 @@
 //! Traits for Advent of Code solutions.
+//!
+//! Solutions implement the traits with infallible parts ([`Part1`],
+//! [`ParsedPart1`], ...) or fallible parts ([`TryPart1`],
+//! [`TryParsedPart1`], ...). The infallible traits are implemented on top of
+//! the fallible traits by blanket implementations, so running and
+//! benchmarking is only implemented once for each kind of solution.
 
+use std::any::Any;
+use std::cell::Cell;
 use std::fmt::Display;
+use std::hint::black_box;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::Once;
+use std::time::Duration;
 
-use crate::{OutputHandler, ParseResult, SolutionPart, measure_time};
+use crate::bench::{BenchOptions, BenchPhase, BenchReport, try_sample};
+use crate::{
+    OutputHandler, ParseResult, PartSelection, RunError, RunResult,
+    SolutionPart, SolveError, SolveResult, measure_time,
+};
+
+thread_local! {
+    /// Whether the current thread is running a function that catches panics,
+    /// so the panic hook stays quiet.
+    static CATCHING_PANIC: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Guards installing the quiet panic hook once.
+static QUIET_HOOK: Once = Once::new();
+
+/// Run a function, catching a panic without the panic hook printing it.
+///
+/// Caught panics are reported as a [`RunError`], so the default hook's
+/// message and backtrace would only repeat it. A hook is installed once
+/// around the existing hook, staying quiet only while a thread is catching,
+/// so panics elsewhere are still printed.
+fn catch_quietly<T>(
+    function: impl FnOnce() -> T,
+) -> Result<T, Box<dyn Any + Send>> {
+    QUIET_HOOK.call_once(|| {
+        let hook = panic::take_hook();
+        panic::set_hook(Box::new(move |info| {
+            if !CATCHING_PANIC.get() {
+                hook(info);
+            }
+        }));
+    });
+
+    let was_catching = CATCHING_PANIC.replace(true);
+    let result = panic::catch_unwind(AssertUnwindSafe(function));
+    CATCHING_PANIC.set(was_catching);
+    result
+}
+
+/// Run a part's function, catching a panic as a [`RunError::PartPanic`].
+fn catch_part<T>(
+    part: SolutionPart,
+    function: impl FnOnce() -> T,
+) -> RunResult<T> {
+    catch_quietly(function).map_err(|payload| RunError::PartPanic {
+        part,
+        message: RunError::panic_message(&*payload),
+    })
+}
+
+/// Run a fallible part's function, catching a panic as a
+/// [`RunError::PartPanic`].
+///
+/// A parse error from the part is returned as a [`RunError::Parse`], and
+/// other solve errors as a [`RunError::Solve`].
+fn catch_solve<T>(
+    part: SolutionPart,
+    function: impl FnOnce() -> SolveResult<T>,
+) -> RunResult<T> {
+    catch_part(part, function)?.map_err(|error| match error {
+        SolveError::Parse(source) => RunError::Parse(source),
+        source => RunError::Solve { part, source },
+    })
+}
+
+/// Run a parsing function, catching a panic as a [`RunError::ParsePanic`].
+fn catch_parse<T>(function: impl FnOnce() -> T) -> RunResult<T> {
+    catch_quietly(function).map_err(|payload| {
+        RunError::ParsePanic(RunError::panic_message(&*payload))
+    })
+}
+
+/// Run a fallible part's function, outputting its answer via the given
+/// output handler.
+///
+/// If `timed` is true, running the part will be timed, with related output
+/// events called.
+fn run_part<T: Display>(
+    handler: &mut dyn OutputHandler,
+    part: SolutionPart,
+    timed: bool,
+    function: impl FnOnce() -> SolveResult<T>,
+) -> RunResult<()> {
+    handler.part_start(part);
+    if timed {
+        let (output, duration) = measure_time!(catch_solve(part, function)?);
+        handler.part_output_timed(part, &output, duration);
+    } else {
+        let output = catch_solve(part, function)?;
+        handler.part_output(part, &output);
+    }
+    Ok(())
+}
+
+/// Sample durations of repeated runs of a fallible part's function with the
+/// given options.
+fn bench_part<T>(
+    part: SolutionPart,
+    options: &BenchOptions,
+    function: impl FnMut() -> SolveResult<T>,
+) -> RunResult<Vec<Duration>> {
+    catch_solve(part, || try_sample(options, function))
+}
+
+/// Check a selection of parts can be run by a solution implementing only
+/// part 1, returning a [`RunError::PartNotImplemented`] if part 2 is the only
+/// part selected.
+fn check_part1_selection(parts: PartSelection) -> RunResult<()> {
+    match parts {
+        PartSelection::Only(SolutionPart::Part2) => {
+            Err(RunError::PartNotImplemented(SolutionPart::Part2))
+        }
+        _ => Ok(()),
+    }
+}
 
 /// A trait to provide a name for a solution.
 ///
@@ -22,11 +148,12 @@ pub trait SolutionName {
 /// It is expected that part 1 can be solved directly from the raw input. If
 /// separate parsing is needed, consider using [`ParsedPart1`] instead.
 ///
-/// For solutions that also implement part 2, see [`Part2`].
+/// For solutions that also implement part 2, see [`Part2`]. Every solution
+/// implementing this trait implements [`TryPart1`], which runs part 1.
 ///
 /// You can make a solution implement
-/// [`RunnableSolution`][crate::RunnableSolution] to call [`Part1::run`] with
-/// the macro [`impl_runnable_solution!`][crate::impl_runnable_solution].
+/// [`RunnableSolution`][crate::RunnableSolution] to call [`TryPart1::run`]
+/// with the macro [`impl_runnable_solution!`][crate::impl_runnable_solution].
 ///
 /// # Examples
 ///
@@ -64,6 +191,118 @@ pub trait Part1: SolutionName {
     /// Implementors may panic if unexpected conditions occur, as Advent of
     /// Code problems generally expect correct inputs.
     fn part1(input: &str) -> ParseResult<Self::Part1Output>;
+}
+
+/// A trait for solutions that implement part 2.
+///
+/// This trait requires that the solution also implements part 1 via
+/// [`Part1`]. Every solution implementing this trait implements [`TryPart2`],
+/// which runs both parts.
+///
+/// You can make a solution implement
+/// [`RunnableSolution`][crate::RunnableSolution] to call [`TryPart2::run`]
+/// with the macro [`impl_runnable_solution!`][crate::impl_runnable_solution].
+///
+/// # Examples
+///
+/// ```
+/// use aoc_framework::{
+///     ParseResult, Part1, Part2, SolutionName, impl_runnable_solution,
+/// };
+///
+/// struct MySolution;
+/// impl SolutionName for MySolution {
+///     const NAME: &'static str = "My Solution";
+/// }
+/// impl Part1 for MySolution {
+///     type Part1Output = usize;
+///     fn part1(input: &str) -> ParseResult<Self::Part1Output> {
+///         Ok(input.len())
+///     }
+/// }
+/// impl Part2 for MySolution {
+///     type Part2Output = usize;
+///     fn part2(input: &str) -> ParseResult<Self::Part2Output> {
+///         Ok(input.len())
+///     }
+/// }
+/// impl_runnable_solution!(MySolution => Part2);
+/// ```
+///
+/// See the crate-level documentation for more examples.
+pub trait Part2: Part1 {
+    /// The type of the output of part 2.
+    type Part2Output: Display;
+
+    /// Solve part 2 of the solution, returning the `Part2Output` type.
+    ///
+    /// # Errors
+    ///
+    /// If parsing fails, a [`ParseError`][crate::ParseError] is returned.
+    ///
+    /// # Panics
+    ///
+    /// Implementors may panic if unexpected conditions occur, as Advent of
+    /// Code problems generally expect correct inputs.
+    fn part2(input: &str) -> ParseResult<Self::Part2Output>;
+}
+
+/// A trait for solutions that implement a fallible part 1.
+///
+/// This is like [`Part1`], except solving part 1 returns a
+/// [`SolveError`][crate::SolveError] when no answer can be found for the
+/// input, rather than panicking. Parse errors convert to a solve error with
+/// `?`, and are still reported as parse errors.
+///
+/// For solutions that also implement part 2, see [`TryPart2`].
+///
+/// You can make a solution implement
+/// [`RunnableSolution`][crate::RunnableSolution] to call [`TryPart1::run`]
+/// with the macro [`impl_runnable_solution!`][crate::impl_runnable_solution].
+///
+/// # Examples
+///
+/// ```
+/// use aoc_framework::{
+///     ParseError, SolutionName, SolveError, SolveResult, TryPart1,
+///     impl_runnable_solution,
+/// };
+///
+/// struct MySolution;
+/// impl SolutionName for MySolution {
+///     const NAME: &'static str = "My Solution";
+/// }
+/// impl TryPart1 for MySolution {
+///     type Part1Output = u32;
+///     fn part1(input: &str) -> SolveResult<Self::Part1Output> {
+///         let number: u32 = input.trim().parse().map_err(|source| {
+///             ParseError::parse_int_from_str(input.trim(), source)
+///         })?;
+///         number
+///             .checked_mul(2)
+///             .ok_or_else(|| SolveError::Overflow("doubling".into()))
+///     }
+/// }
+/// impl_runnable_solution!(MySolution => TryPart1);
+/// ```
+///
+/// See the crate-level documentation for more examples.
+pub trait TryPart1: SolutionName {
+    /// The type of the output of part 1.
+    type Part1Output: Display;
+
+    /// Solve part 1 of the solution, returning the `Part1Output` type.
+    ///
+    /// # Errors
+    ///
+    /// If parsing fails or no answer can be found for the input, a
+    /// [`SolveError`][crate::SolveError] is returned.
+    ///
+    /// # Panics
+    ///
+    /// Implementors may panic if unexpected conditions occur, as Advent of
+    /// Code problems generally expect correct inputs.
+    fn part1(input: &str) -> SolveResult<Self::Part1Output>;
 
     /// Run part 1 of the solution, outputting results via the given output
     /// handler.
@@ -73,25 +312,62 @@ pub trait Part1: SolutionName {
     ///
     /// # Errors
     ///
-    /// If parsing fails, a [`ParseError`][crate::ParseError] is returned.
+    /// If parsing fails, a [`RunError::Parse`][crate::RunError::Parse] is
+    /// returned. If solving part 1 fails, a
+    /// [`RunError::Solve`][crate::RunError::Solve] is returned. If running
+    /// part 1 panics, the panic is caught and returned as a
+    /// [`RunError::PartPanic`][crate::RunError::PartPanic].
+    fn run_part1(
+        handler: &mut dyn OutputHandler,
+        input: &str,
+        timed: bool,
+    ) -> RunResult<()> {
+        run_part(handler, SolutionPart::Part1, timed, || Self::part1(input))
+    }
+
+    /// Benchmark part 1 of the solution, sampling durations of repeated runs
+    /// with the given options.
     ///
-    /// # Panics
+    /// # Errors
     ///
-    /// A solution part's implementation may panic if unexpected conditions
-    /// occur, as Advent of Code problems generally expect correct inputs.
-    fn run_part1(
+    /// If parsing or solving part 1 fails, or running part 1 panics, a
+    /// [`RunError`][crate::RunError] is returned.
+    fn bench_part1(
+        input: &str,
+        options: &BenchOptions,
+    ) -> RunResult<Vec<Duration>> {
+        bench_part(SolutionPart::Part1, options, || {
+            Self::part1(black_box(input))
+        })
+    }
+
+    /// Run selected parts of the solution, outputting results via the given
+    /// output handler.
+    ///
+    /// This will only run part 1 of this trait, if selected.
+    ///
+    /// If `timed` is true, running selected parts will be timed, with related
+    /// output events called.
+    ///
+    /// # Errors
+    ///
+    /// If only part 2 is selected, a
+    /// [`RunError::PartNotImplemented`][crate::RunError::PartNotImplemented]
+    /// is returned before running anything. If parsing fails, a
+    /// [`RunError::Parse`][crate::RunError::Parse] is returned. If solving a
+    /// part fails, a [`RunError::Solve`][crate::RunError::Solve] is returned.
+    /// If running a part panics, the panic is caught and returned as a
+    /// [`RunError`][crate::RunError].
+    fn run_parts(
         handler: &mut dyn OutputHandler,
         input: &str,
+        parts: PartSelection,
         timed: bool,
-    ) -> ParseResult<()> {
-        let part = SolutionPart::Part1;
-        handler.part_start(part);
-        if timed {
-            let (output, duration) = measure_time!(Self::part1(input)?);
-            handler.part_output_timed(part, &output, duration);
-        } else {
-            let output = Self::part1(input)?;
-            handler.part_output(part, &output);
+    ) -> RunResult<()> {
+        check_part1_selection(parts)?;
+        Self::output_name(handler);
+        if parts.includes(SolutionPart::Part1) {
+            Self::run_part1(handler, input, timed)?;
         }
         Ok(())
     }
@@ -105,59 +381,118 @@ pub trait Part1: SolutionName {
     ///
     /// # Errors
     ///
-    /// If parsing fails, a [`ParseError`][crate::ParseError] is returned.
-    ///
-    /// # Panics
-    ///
-    /// A solution part's implementation may panic if unexpected conditions
-    /// occur, as Advent of Code problems generally expect correct inputs.
+    /// If parsing fails, a [`RunError::Parse`][crate::RunError::Parse] is
+    /// returned. If solving a part fails, a
+    /// [`RunError::Solve`][crate::RunError::Solve] is returned. If running a
+    /// part panics, the panic is caught and returned as a
+    /// [`RunError`][crate::RunError].
     fn run(
         handler: &mut dyn OutputHandler,
         input: &str,
         timed: bool,
-    ) -> ParseResult<()> {
-        Self::output_name(handler);
-        Self::run_part1(handler, input, timed)
+    ) -> RunResult<()> {
+        Self::run_parts(handler, input, PartSelection::All, timed)
+    }
+
+    /// Benchmark the solution, sampling durations of repeated runs with the
+    /// given options.
+    ///
+    /// This will benchmark only part 1 of this trait.
+    ///
+    /// # Errors
+    ///
+    /// If only part 2 is selected, a
+    /// [`RunError::PartNotImplemented`][crate::RunError::PartNotImplemented]
+    /// is returned. If parsing or solving a part fails, or running a part
+    /// panics, a [`RunError`][crate::RunError] is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use aoc_framework::bench::BenchOptions;
+    /// use aoc_framework::{
+    ///     ParseResult, Part1, PartSelection, RunError, SolutionName,
+    ///     SolutionPart, TryPart1,
+    /// };
+    ///
+    /// struct MySolution;
+    /// impl SolutionName for MySolution {
+    ///     const NAME: &'static str = "My Solution";
+    /// }
+    /// impl Part1 for MySolution {
+    ///     type Part1Output = usize;
+    ///     fn part1(input: &str) -> ParseResult<Self::Part1Output> {
+    ///         Ok(input.len())
+    ///     }
+    /// }
+    ///
+    /// let options = BenchOptions {
+    ///     parts: PartSelection::Only(SolutionPart::Part2),
+    ///     ..BenchOptions::default()
+    /// };
+    /// let result = <MySolution as TryPart1>::bench("input", &options);
+    /// assert!(matches!(
+    ///     result,
+    ///     Err(RunError::PartNotImplemented(SolutionPart::Part2))
+    /// ));
+    /// ```
+    fn bench(input: &str, options: &BenchOptions) -> RunResult<BenchReport> {
+        check_part1_selection(options.parts)?;
+        let mut report = BenchReport::default();
+        if options.parts.includes(SolutionPart::Part1) {
+            let part1 = Self::bench_part1(input, options)?;
+            report.push(BenchPhase::Part(SolutionPart::Part1), &part1);
+        }
+        Ok(report)
     }
 }
 
-/// A trait for solutions that implement part 2.
+/// A trait for solutions that implement a fallible part 2.
+///
+/// This is like [`Part2`], except solving part 2 returns a
+/// [`SolveError`][crate::SolveError] when no answer can be found for the
+/// input, rather than panicking.
 ///
 /// This trait requires that the solution also implements part 1 via
-/// [`Part1`].
+/// [`TryPart1`].
 ///
 /// You can make a solution implement
-/// [`RunnableSolution`][crate::RunnableSolution] to call [`Part2::run`] with
-/// the macro [`impl_runnable_solution!`][crate::impl_runnable_solution].
+/// [`RunnableSolution`][crate::RunnableSolution] to call [`TryPart2::run`]
+/// with the macro [`impl_runnable_solution!`][crate::impl_runnable_solution].
 ///
 /// # Examples
 ///
 /// ```
 /// use aoc_framework::{
-///     ParseResult, Part1, Part2, SolutionName, impl_runnable_solution,
+///     SolutionName, SolveError, SolveResult, TryPart1, TryPart2,
+///     impl_runnable_solution,
 /// };
 ///
 /// struct MySolution;
 /// impl SolutionName for MySolution {
 ///     const NAME: &'static str = "My Solution";
 /// }
-/// impl Part1 for MySolution {
+/// impl TryPart1 for MySolution {
 ///     type Part1Output = usize;
-///     fn part1(input: &str) -> ParseResult<Self::Part1Output> {
-///         Ok(input.len())
+///     fn part1(input: &str) -> SolveResult<Self::Part1Output> {
+///         Ok(input.lines().count())
 ///     }
 /// }
-/// impl Part2 for MySolution {
+/// impl TryPart2 for MySolution {
 ///     type Part2Output = usize;
-///     fn part2(input: &str) -> ParseResult<Self::Part2Output> {
-///         Ok(input.len())
+///     fn part2(input: &str) -> SolveResult<Self::Part2Output> {
+///         input
+///             .lines()
+///             .map(str::len)
+///             .max()
+///             .ok_or_else(|| SolveError::NoSolution("no lines".into()))
 ///     }
 /// }
-/// impl_runnable_solution!(MySolution => Part2);
+/// impl_runnable_solution!(MySolution => TryPart2);
 /// ```
 ///
 /// See the crate-level documentation for more examples.
-pub trait Part2: Part1 {
+pub trait TryPart2: TryPart1 {
     /// The type of the output of part 2.
     type Part2Output: Display;
 
@@ -165,13 +500,14 @@ pub trait Part2: Part1 {
     ///
     /// # Errors
     ///
-    /// If parsing fails, a [`ParseError`][crate::ParseError] is returned.
+    /// If parsing fails or no answer can be found for the input, a
+    /// [`SolveError`][crate::SolveError] is returned.
     ///
     /// # Panics
     ///
     /// Implementors may panic if unexpected conditions occur, as Advent of
     /// Code problems generally expect correct inputs.
-    fn part2(input: &str) -> ParseResult<Self::Part2Output>;
+    fn part2(input: &str) -> SolveResult<Self::Part2Output>;
 
     /// Run part 2 of the solution, outputting results via the given output
     /// handler.
@@ -181,25 +517,63 @@ pub trait Part2: Part1 {
     ///
     /// # Errors
     ///
-    /// If parsing fails, a [`ParseError`][crate::ParseError] is returned.
+    /// If parsing fails, a [`RunError::Parse`][crate::RunError::Parse] is
+    /// returned. If solving part 2 fails, a
+    /// [`RunError::Solve`][crate::RunError::Solve] is returned. If running
+    /// part 2 panics, the panic is caught and returned as a
+    /// [`RunError::PartPanic`][crate::RunError::PartPanic].
+    fn run_part2(
+        handler: &mut dyn OutputHandler,
+        input: &str,
+        timed: bool,
+    ) -> RunResult<()> {
+        run_part(handler, SolutionPart::Part2, timed, || Self::part2(input))
+    }
+
+    /// Benchmark part 2 of the solution, sampling durations of repeated runs
+    /// with the given options.
     ///
-    /// # Panics
+    /// # Errors
     ///
-    /// A solution part's implementation may panic if unexpected conditions
-    /// occur, as Advent of Code problems generally expect correct inputs.
-    fn run_part2(
+    /// If parsing or solving part 2 fails, or running part 2 panics, a
+    /// [`RunError`][crate::RunError] is returned.
+    fn bench_part2(
+        input: &str,
+        options: &BenchOptions,
+    ) -> RunResult<Vec<Duration>> {
+        bench_part(SolutionPart::Part2, options, || {
+            Self::part2(black_box(input))
+        })
+    }
+
+    /// Run selected parts of the solution, outputting results via the given
+    /// output handler.
+    ///
+    /// This will run part 1 and part 2 of this trait and its supertrait
+    /// [`TryPart1`], if selected.
+    ///
+    /// If `timed` is true, running selected parts will be timed, with related
+    /// output events called.
+    ///
+    /// # Errors
+    ///
+    /// If parsing fails, a [`RunError::Parse`][crate::RunError::Parse] is
+    /// returned. If solving a part fails, a
+    /// [`RunError::Solve`][crate::RunError::Solve] is returned. If running a
+    /// part panics, the panic is caught and returned as a
+    /// [`RunError`][crate::RunError].
+    fn run_parts(
         handler: &mut dyn OutputHandler,
         input: &str,
+        parts: PartSelection,
         timed: bool,
-    ) -> ParseResult<()> {
-        let part = SolutionPart::Part2;
-        handler.part_start(part);
-        if timed {
-            let (output, duration) = measure_time!(Self::part2(input)?);
-            handler.part_output_timed(part, &output, duration);
-        } else {
-            let output = Self::part2(input)?;
-            handler.part_output(part, &output);
+    ) -> RunResult<()> {
+        Self::output_name(handler);
+        if parts.includes(SolutionPart::Part1) {
+            Self::run_part1(handler, input, timed)?;
+        }
+        if parts.includes(SolutionPart::Part2) {
+            Self::run_part2(handler, input, timed)?;
         }
         Ok(())
     }
@@ -207,27 +581,63 @@ pub trait Part2: Part1 {
     /// Run the solution, outputting results via the given output handler.
     ///
     /// This will run both part 1 and part 2 of this trait and its supertrait
-    /// [`Part1`].
+    /// [`TryPart1`].
     ///
     /// If `timed` is true, running parts will be timed, with related output
     /// events called.
     ///
     /// # Errors
     ///
-    /// If parsing fails, a [`ParseError`][crate::ParseError] is returned.
-    ///
-    /// # Panics
-    ///
-    /// A solution part's implementation may panic if unexpected conditions
-    /// occur, as Advent of Code problems generally expect correct inputs.
+    /// If parsing fails, a [`RunError::Parse`][crate::RunError::Parse] is
+    /// returned. If solving a part fails, a
+    /// [`RunError::Solve`][crate::RunError::Solve] is returned. If running a
+    /// part panics, the panic is caught and returned as a
+    /// [`RunError`][crate::RunError].
     fn run(
         handler: &mut dyn OutputHandler,
         input: &str,
         timed: bool,
-    ) -> ParseResult<()> {
-        Self::output_name(handler);
-        Self::run_part1(handler, input, timed)?;
-        Self::run_part2(handler, input, timed)
+    ) -> RunResult<()> {
+        <Self as TryPart2>::run_parts(handler, input, PartSelection::All, timed)
+    }
+
+    /// Benchmark the solution, sampling durations of repeated runs with the
+    /// given options.
+    ///
+    /// This will benchmark both part 1 and part 2 of this trait and its
+    /// supertrait [`TryPart1`].
+    ///
+    /// # Errors
+    ///
+    /// If parsing or solving a part fails, or running a part panics, a
+    /// [`RunError`][crate::RunError] is returned.
+    fn bench(input: &str, options: &BenchOptions) -> RunResult<BenchReport> {
+        let mut report = BenchReport::default();
+        if options.parts.includes(SolutionPart::Part1) {
+            let part1 = Self::bench_part1(input, options)?;
+            report.push(BenchPhase::Part(SolutionPart::Part1), &part1);
+        }
+        if options.parts.includes(SolutionPart::Part2) {
+            let part2 = Self::bench_part2(input, options)?;
+            report.push(BenchPhase::Part(SolutionPart::Part2), &part2);
+        }
+        Ok(report)
+    }
+}
+
+impl<S: Part1> TryPart1 for S {
+    type Part1Output = S::Part1Output;
+
+    fn part1(input: &str) -> SolveResult<Self::Part1Output> {
+        Ok(<S as Part1>::part1(input)?)
+    }
+}
+
+impl<S: Part2> TryPart2 for S {
+    type Part2Output = S::Part2Output;
+
+    fn part2(input: &str) -> SolveResult<Self::Part2Output> {
+        Ok(<S as Part2>::part2(input)?)
     }
 }
 
@@ -239,11 +649,14 @@ pub trait Part2: Part1 {
 /// using [`Part1`] if separate parsing is not needed, or [`Part2`] if both
 /// parts require separate parsing.
 ///
-/// For solutions that also implement part 2, see [`ParsedPart2`].
+/// For solutions that also implement part 2, see [`ParsedPart2`]. Every
+/// solution implementing this trait implements [`TryParsedPart1`], which
+/// runs parsing and part 1.
 ///
 /// You can make a solution implement
-/// [`RunnableSolution`][crate::RunnableSolution] to call [`ParsedPart1::run`]
-/// with the macro [`impl_runnable_solution!`][crate::impl_runnable_solution].
+/// [`RunnableSolution`][crate::RunnableSolution] to call
+/// [`TryParsedPart1::run`] with the macro
+/// [`impl_runnable_solution!`][crate::impl_runnable_solution].
 ///
 /// # Examples
 ///
@@ -281,6 +694,131 @@ pub trait ParsedPart1: SolutionName {
     /// If parsing fails, a [`ParseError`][crate::ParseError] is returned.
     fn parse(input: &str) -> ParseResult<Self::ParsedInput>;
 
+    /// The type of the output of part 1.
+    type Part1Output: Display;
+
+    /// Solve part 1 of the solution, returning the `Part1Output` type.
+    ///
+    /// # Panics
+    ///
+    /// Implementors may panic if unexpected conditions occur, as Advent of
+    /// Code problems generally expect correct inputs.
+    fn part1(parsed: &Self::ParsedInput) -> Self::Part1Output;
+}
+
+/// A trait for solutions that implement part 2 with parsed input.
+///
+/// It is expected that input is parsed once, then the resulting parsed data
+/// is used for both parts.
+///
+/// This trait requires that the solution also implements part 1 via
+/// [`ParsedPart1`]. Every solution implementing this trait implements
+/// [`TryParsedPart2`], which runs parsing and both parts.
+///
+/// You can make a solution implement
+/// [`RunnableSolution`][crate::RunnableSolution] to call
+/// [`TryParsedPart2::run`] with the macro
+/// [`impl_runnable_solution!`][crate::impl_runnable_solution].
+///
+/// # Examples
+///
+/// ```
+/// use aoc_framework::{
+///     ParseResult, ParsedPart1, ParsedPart2, SolutionName,
+///     impl_runnable_solution,
+/// };
+///
+/// struct MySolution;
+/// impl SolutionName for MySolution {
+///     const NAME: &'static str = "My Solution";
+/// }
+/// impl ParsedPart1 for MySolution {
+///     type ParsedInput = Vec<String>;
+///     fn parse(input: &str) -> ParseResult<Self::ParsedInput> {
+///         Ok(input.lines().map(|line| line.to_string()).collect())
+///     }
+///     type Part1Output = usize;
+///     fn part1(parsed: &Self::ParsedInput) -> Self::Part1Output {
+///         parsed.len()
+///     }
+/// }
+/// impl ParsedPart2 for MySolution {
+///     type Part2Output = usize;
+///     fn part2(parsed: &Self::ParsedInput) -> Self::Part2Output {
+///         parsed.len()
+///     }
+/// }
+/// impl_runnable_solution!(MySolution => ParsedPart2);
+/// ```
+///
+/// See the crate-level documentation for more examples.
+pub trait ParsedPart2: ParsedPart1 {
+    /// The type of the output of part 2.
+    type Part2Output: Display;
+
+    /// Solve part 2 of the solution, returning the `Part2Output` type.
+    ///
+    /// # Panics
+    ///
+    /// Implementors may panic if unexpected conditions occur, as Advent of
+    /// Code problems generally expect correct inputs.
+    fn part2(parsed: &Self::ParsedInput) -> Self::Part2Output;
+}
+
+/// A trait for solutions that implement a fallible part 1 with parsed input.
+///
+/// This is like [`ParsedPart1`], except solving part 1 returns a
+/// [`SolveError`][crate::SolveError] when no answer can be found for the
+/// parsed input, rather than panicking.
+///
+/// For solutions that also implement part 2, see [`TryParsedPart2`].
+///
+/// You can make a solution implement
+/// [`RunnableSolution`][crate::RunnableSolution] to call
+/// [`TryParsedPart1::run`] with the macro
+/// [`impl_runnable_solution!`][crate::impl_runnable_solution].
+///
+/// # Examples
+///
+/// ```
+/// use aoc_framework::{
+///     ParseResult, SolutionName, SolveError, SolveResult, TryParsedPart1,
+///     impl_runnable_solution,
+/// };
+///
+/// struct MySolution;
+/// impl SolutionName for MySolution {
+///     const NAME: &'static str = "My Solution";
+/// }
+/// impl TryParsedPart1 for MySolution {
+///     type ParsedInput = Vec<String>;
+///     fn parse(input: &str) -> ParseResult<Self::ParsedInput> {
+///         Ok(input.lines().map(|line| line.to_string()).collect())
+///     }
+///     type Part1Output = usize;
+///     fn part1(parsed: &Self::ParsedInput) -> SolveResult<Self::Part1Output> {
+///         parsed
+///             .iter()
+///             .map(String::len)
+///             .max()
+///             .ok_or_else(|| SolveError::NoSolution("no lines".into()))
+///     }
+/// }
+/// impl_runnable_solution!(MySolution => TryParsedPart1);
+/// ```
+///
+/// See the crate-level documentation for more examples.
+pub trait TryParsedPart1: SolutionName {
+    /// The data that represents the parsed input.
+    type ParsedInput;
+
+    /// Parse the raw input string into the solution's `ParsedInput` type.
+    ///
+    /// # Errors
+    ///
+    /// If parsing fails, a [`ParseError`][crate::ParseError] is returned.
+    fn parse(input: &str) -> ParseResult<Self::ParsedInput>;
+
     /// Run parsing of the input, outputting progress via the given output
     /// handler.
     ///
@@ -289,34 +827,65 @@ pub trait ParsedPart1: SolutionName {
     ///
     /// # Errors
     ///
-    /// If parsing fails, a [`ParseError`][crate::ParseError] is returned.
+    /// If parsing fails, a [`RunError::Parse`][crate::RunError::Parse] is
+    /// returned. If parsing panics, the panic is caught and returned as a
+    /// [`RunError::ParsePanic`][crate::RunError::ParsePanic].
     fn run_parse(
         handler: &mut dyn OutputHandler,
         input: &str,
         timed: bool,
-    ) -> ParseResult<Self::ParsedInput> {
+    ) -> RunResult<Self::ParsedInput> {
         handler.parse_start();
         if timed {
-            let (parsed, duration) = measure_time!(Self::parse(input)?);
+            let (parsed, duration) =
+                measure_time!(catch_parse(|| Self::parse(input))??);
             handler.parse_end_timed(duration);
             Ok(parsed)
         } else {
-            let parsed = Self::parse(input)?;
+            let parsed = catch_parse(|| Self::parse(input))??;
             handler.parse_end();
             Ok(parsed)
         }
     }
 
+    /// Benchmark parsing of the input, sampling durations of repeated runs
+    /// with the given options.
+    ///
+    /// The input is parsed once more after sampling, returning the parsed
+    /// input alongside the samples.
+    ///
+    /// # Errors
+    ///
+    /// If parsing fails, a [`RunError::Parse`][crate::RunError::Parse] is
+    /// returned. If parsing panics, the panic is caught and returned as a
+    /// [`RunError::ParsePanic`][crate::RunError::ParsePanic].
+    fn bench_parse(
+        input: &str,
+        options: &BenchOptions,
+    ) -> RunResult<(Self::ParsedInput, Vec<Duration>)> {
+        catch_parse(|| {
+            let samples =
+                try_sample(options, || Self::parse(black_box(input)))?;
+            Self::parse(input).map(|parsed| (parsed, samples))
+        })?
+        .map_err(RunError::from)
+    }
+
     /// The type of the output of part 1.
     type Part1Output: Display;
 
     /// Solve part 1 of the solution, returning the `Part1Output` type.
     ///
+    /// # Errors
+    ///
+    /// If no answer can be found for the parsed input, a
+    /// [`SolveError`][crate::SolveError] is returned.
+    ///
     /// # Panics
     ///
     /// Implementors may panic if unexpected conditions occur, as Advent of
     /// Code problems generally expect correct inputs.
-    fn part1(parsed: &Self::ParsedInput) -> Self::Part1Output;
+    fn part1(parsed: &Self::ParsedInput) -> SolveResult<Self::Part1Output>;
 
     /// Run part 1 of the solution, outputting results via the given output
     /// handler.
@@ -324,24 +893,65 @@ pub trait ParsedPart1: SolutionName {
     /// If `timed` is true, running part 1 will be timed, with related output
     /// events called.
     ///
-    /// # Panics
+    /// # Errors
     ///
-    /// A solution part's implementation may panic if unexpected conditions
-    /// occur, as Advent of Code problems generally expect correct inputs.
+    /// If solving part 1 fails, a [`RunError::Solve`][crate::RunError::Solve]
+    /// is returned. If running part 1 panics, the panic is caught and returned
+    /// as a [`RunError::PartPanic`][crate::RunError::PartPanic].
     fn run_part1(
         handler: &mut dyn OutputHandler,
         parsed: &Self::ParsedInput,
         timed: bool,
-    ) {
-        let part = SolutionPart::Part1;
-        handler.part_start(part);
-        if timed {
-            let (output, duration) = measure_time!(Self::part1(parsed));
-            handler.part_output_timed(part, &output, duration);
-        } else {
-            let output = Self::part1(parsed);
-            handler.part_output(part, &output);
+    ) -> RunResult<()> {
+        run_part(handler, SolutionPart::Part1, timed, || Self::part1(parsed))
+    }
+
+    /// Benchmark part 1 of the solution, sampling durations of repeated runs
+    /// with the given options.
+    ///
+    /// # Errors
+    ///
+    /// If solving part 1 fails, or running part 1 panics, a
+    /// [`RunError`][crate::RunError] is returned.
+    fn bench_part1(
+        parsed: &Self::ParsedInput,
+        options: &BenchOptions,
+    ) -> RunResult<Vec<Duration>> {
+        bench_part(SolutionPart::Part1, options, || {
+            Self::part1(black_box(parsed))
+        })
+    }
+
+    /// Run selected parts of the solution, outputting results via the given
+    /// output handler.
+    ///
+    /// This will run parsing, and part 1 of this trait if selected.
+    ///
+    /// If `timed` is true, parsing and running selected parts will be
+    /// timed, with related output events called.
+    ///
+    /// # Errors
+    ///
+    /// If only part 2 is selected, a
+    /// [`RunError::PartNotImplemented`][crate::RunError::PartNotImplemented]
+    /// is returned before parsing. If parsing fails, a
+    /// [`RunError::Parse`][crate::RunError::Parse] is returned. If solving a
+    /// part fails, a [`RunError::Solve`][crate::RunError::Solve] is returned.
+    /// If parsing or running a part panics, the panic is caught and returned
+    /// as a [`RunError`][crate::RunError].
+    fn run_parts(
+        handler: &mut dyn OutputHandler,
+        input: &str,
+        parts: PartSelection,
+        timed: bool,
+    ) -> RunResult<()> {
+        check_part1_selection(parts)?;
+        Self::output_name(handler);
+        let parsed = Self::run_parse(handler, input, timed)?;
+        if parts.includes(SolutionPart::Part1) {
+            Self::run_part1(handler, &parsed, timed)?;
         }
+        Ok(())
     }
 
     /// Run the solution, outputting results via the given output handler.
@@ -353,79 +963,114 @@ pub trait ParsedPart1: SolutionName {
     ///
     /// # Errors
     ///
-    /// If parsing fails, a [`ParseError`][crate::ParseError] is returned.
-    ///
-    /// # Panics
-    ///
-    /// A solution part's implementation may panic if unexpected conditions
-    /// occur, as Advent of Code problems generally expect correct inputs.
+    /// If parsing fails, a [`RunError::Parse`][crate::RunError::Parse] is
+    /// returned. If solving a part fails, a
+    /// [`RunError::Solve`][crate::RunError::Solve] is returned. If parsing or
+    /// running a part panics, the panic is caught and returned as a
+    /// [`RunError`][crate::RunError].
     fn run(
         handler: &mut dyn OutputHandler,
         input: &str,
         timed: bool,
-    ) -> ParseResult<()> {
-        Self::output_name(handler);
-        let parsed = Self::run_parse(handler, input, timed)?;
-        Self::run_part1(handler, &parsed, timed);
-        Ok(())
+    ) -> RunResult<()> {
+        Self::run_parts(handler, input, PartSelection::All, timed)
+    }
+
+    /// Benchmark the solution, sampling durations of repeated runs with the
+    /// given options.
+    ///
+    /// This will benchmark parsing and part 1 of this trait, running part 1
+    /// with input parsed once.
+    ///
+    /// # Errors
+    ///
+    /// If only part 2 is selected, a
+    /// [`RunError::PartNotImplemented`][crate::RunError::PartNotImplemented]
+    /// is returned. If parsing or solving a part fails, or parsing or running
+    /// a part panics, a [`RunError`][crate::RunError] is returned.
+    fn bench(input: &str, options: &BenchOptions) -> RunResult<BenchReport> {
+        check_part1_selection(options.parts)?;
+        let mut report = BenchReport::default();
+        let (parsed, parse) = Self::bench_parse(input, options)?;
+        report.push(BenchPhase::Parse, &parse);
+        if options.parts.includes(SolutionPart::Part1) {
+            let part1 = Self::bench_part1(&parsed, options)?;
+            report.push(BenchPhase::Part(SolutionPart::Part1), &part1);
+        }
+        Ok(report)
     }
 }
 
-/// A trait for solutions that implement part 2 with parsed input.
+/// A trait for solutions that implement a fallible part 2 with parsed input.
 ///
-/// It is expected that input is parsed once, then the resulting parsed data
-/// is used for both parts.
+/// This is like [`ParsedPart2`], except solving part 2 returns a
+/// [`SolveError`][crate::SolveError] when no answer can be found for the
+/// parsed input, rather than panicking.
 ///
 /// This trait requires that the solution also implements part 1 via
-/// [`ParsedPart1`].
+/// [`TryParsedPart1`].
 ///
 /// You can make a solution implement
-/// [`RunnableSolution`][crate::RunnableSolution] to call [`ParsedPart2::run`]
-/// with the macro [`impl_runnable_solution!`][crate::impl_runnable_solution].
+/// [`RunnableSolution`][crate::RunnableSolution] to call
+/// [`TryParsedPart2::run`] with the macro
+/// [`impl_runnable_solution!`][crate::impl_runnable_solution].
 ///
 /// # Examples
 ///
 /// ```
 /// use aoc_framework::{
-///     ParseResult, ParsedPart1, ParsedPart2, SolutionName,
-///     impl_runnable_solution,
+///     ParseResult, SolutionName, SolveError, SolveResult, TryParsedPart1,
+///     TryParsedPart2, impl_runnable_solution,
 /// };
 ///
 /// struct MySolution;
 /// impl SolutionName for MySolution {
 ///     const NAME: &'static str = "My Solution";
 /// }
-/// impl ParsedPart1 for MySolution {
+/// impl TryParsedPart1 for MySolution {
 ///     type ParsedInput = Vec<String>;
 ///     fn parse(input: &str) -> ParseResult<Self::ParsedInput> {
 ///         Ok(input.lines().map(|line| line.to_string()).collect())
 ///     }
 ///     type Part1Output = usize;
-///     fn part1(parsed: &Self::ParsedInput) -> Self::Part1Output {
-///         parsed.len()
+///     fn part1(parsed: &Self::ParsedInput) -> SolveResult<Self::Part1Output> {
+///         parsed
+///             .iter()
+///             .map(String::len)
+///             .max()
+///             .ok_or_else(|| SolveError::NoSolution("no lines".into()))
 ///     }
 /// }
-/// impl ParsedPart2 for MySolution {
+/// impl TryParsedPart2 for MySolution {
 ///     type Part2Output = usize;
-///     fn part2(parsed: &Self::ParsedInput) -> Self::Part2Output {
-///         parsed.len()
+///     fn part2(parsed: &Self::ParsedInput) -> SolveResult<Self::Part2Output> {
+///         parsed.iter().map(String::len).try_fold(0usize, |sum, len| {
+///             sum.checked_add(len).ok_or_else(|| {
+///                 SolveError::Overflow("sum of lengths".into())
+///             })
+///         })
 ///     }
 /// }
-/// impl_runnable_solution!(MySolution => ParsedPart2);
+/// impl_runnable_solution!(MySolution => TryParsedPart2);
 /// ```
 ///
 /// See the crate-level documentation for more examples.
-pub trait ParsedPart2: ParsedPart1 {
+pub trait TryParsedPart2: TryParsedPart1 {
     /// The type of the output of part 2.
     type Part2Output: Display;
 
     /// Solve part 2 of the solution, returning the `Part2Output` type.
     ///
+    /// # Errors
+    ///
+    /// If no answer can be found for the parsed input, a
+    /// [`SolveError`][crate::SolveError] is returned.
+    ///
     /// # Panics
     ///
     /// Implementors may panic if unexpected conditions occur, as Advent of
     /// Code problems generally expect correct inputs.
-    fn part2(parsed: &Self::ParsedInput) -> Self::Part2Output;
+    fn part2(parsed: &Self::ParsedInput) -> SolveResult<Self::Part2Output>;
 
     /// Run part 2 of the solution, outputting results via the given output
     /// handler.
@@ -433,51 +1078,140 @@ pub trait ParsedPart2: ParsedPart1 {
     /// If `timed` is true, running part 2 will be timed, with related output
     /// events called.
     ///
-    /// # Panics
+    /// # Errors
     ///
-    /// A solution part's implementation may panic if unexpected conditions
-    /// occur, as Advent of Code problems generally expect correct inputs.
+    /// If solving part 2 fails, a [`RunError::Solve`][crate::RunError::Solve]
+    /// is returned. If running part 2 panics, the panic is caught and returned
+    /// as a [`RunError::PartPanic`][crate::RunError::PartPanic].
     fn run_part2(
         handler: &mut dyn OutputHandler,
         parsed: &Self::ParsedInput,
         timed: bool,
-    ) {
-        let part = SolutionPart::Part2;
-        handler.part_start(part);
-        if timed {
-            let (output, duration) = measure_time!(Self::part2(parsed));
-            handler.part_output_timed(part, &output, duration);
-        } else {
-            let output = Self::part2(parsed);
-            handler.part_output(part, &output);
+    ) -> RunResult<()> {
+        run_part(handler, SolutionPart::Part2, timed, || Self::part2(parsed))
+    }
+
+    /// Benchmark part 2 of the solution, sampling durations of repeated runs
+    /// with the given options.
+    ///
+    /// # Errors
+    ///
+    /// If solving part 2 fails, or running part 2 panics, a
+    /// [`RunError`][crate::RunError] is returned.
+    fn bench_part2(
+        parsed: &Self::ParsedInput,
+        options: &BenchOptions,
+    ) -> RunResult<Vec<Duration>> {
+        bench_part(SolutionPart::Part2, options, || {
+            Self::part2(black_box(parsed))
+        })
+    }
+
+    /// Run selected parts of the solution, outputting results via the given
+    /// output handler.
+    ///
+    /// This will run parsing, and part 1 and part 2 of this trait and its
+    /// supertrait [`TryParsedPart1`] if selected.
+    ///
+    /// If `timed` is true, parsing and running selected parts will be
+    /// timed, with related output events called.
+    ///
+    /// # Errors
+    ///
+    /// If parsing fails, a [`RunError::Parse`][crate::RunError::Parse] is
+    /// returned. If solving a part fails, a
+    /// [`RunError::Solve`][crate::RunError::Solve] is returned. If parsing or
+    /// running a part panics, the panic is caught and returned as a
+    /// [`RunError`][crate::RunError].
+    fn run_parts(
+        handler: &mut dyn OutputHandler,
+        input: &str,
+        parts: PartSelection,
+        timed: bool,
+    ) -> RunResult<()> {
+        Self::output_name(handler);
+        let parsed = Self::run_parse(handler, input, timed)?;
+        if parts.includes(SolutionPart::Part1) {
+            Self::run_part1(handler, &parsed, timed)?;
+        }
+        if parts.includes(SolutionPart::Part2) {
+            Self::run_part2(handler, &parsed, timed)?;
         }
+        Ok(())
     }
 
     /// Run the solution, outputting results via the given output handler.
     ///
     /// This will run parsing, part 1, and part 2 of this trait and its
-    /// supertrait [`ParsedPart1`].
+    /// supertrait [`TryParsedPart1`].
     ///
     /// If `timed` is true, parsing and running parts will be timed, with
     /// related output events called.
     ///
     /// # Errors
     ///
-    /// If parsing fails, a [`ParseError`][crate::ParseError] is returned.
-    ///
-    /// # Panics
-    ///
-    /// A solution part's implementation may panic if unexpected conditions
-    /// occur, as Advent of Code problems generally expect correct inputs.
+    /// If parsing fails, a [`RunError::Parse`][crate::RunError::Parse] is
+    /// returned. If solving a part fails, a
+    /// [`RunError::Solve`][crate::RunError::Solve] is returned. If parsing or
+    /// running a part panics, the panic is caught and returned as a
+    /// [`RunError`][crate::RunError].
     fn run(
         handler: &mut dyn OutputHandler,
         input: &str,
         timed: bool,
-    ) -> ParseResult<()> {
-        Self::output_name(handler);
-        let parsed = Self::run_parse(handler, input, timed)?;
-        Self::run_part1(handler, &parsed, timed);
-        Self::run_part2(handler, &parsed, timed);
-        Ok(())
+    ) -> RunResult<()> {
+        <Self as TryParsedPart2>::run_parts(
+            handler,
+            input,
+            PartSelection::All,
+            timed,
+        )
+    }
+
+    /// Benchmark the solution, sampling durations of repeated runs with the
+    /// given options.
+    ///
+    /// This will benchmark parsing, part 1, and part 2 of this trait and its
+    /// supertrait [`TryParsedPart1`], running parts with input parsed once.
+    ///
+    /// # Errors
+    ///
+    /// If parsing or solving a part fails, or parsing or running a part
+    /// panics, a [`RunError`][crate::RunError] is returned.
+    fn bench(input: &str, options: &BenchOptions) -> RunResult<BenchReport> {
+        let mut report = BenchReport::default();
+        let (parsed, parse) = Self::bench_parse(input, options)?;
+        report.push(BenchPhase::Parse, &parse);
+        if options.parts.includes(SolutionPart::Part1) {
+            let part1 = Self::bench_part1(&parsed, options)?;
+            report.push(BenchPhase::Part(SolutionPart::Part1), &part1);
+        }
+        if options.parts.includes(SolutionPart::Part2) {
+            let part2 = Self::bench_part2(&parsed, options)?;
+            report.push(BenchPhase::Part(SolutionPart::Part2), &part2);
+        }
+        Ok(report)
+    }
+}
+
+impl<S: ParsedPart1> TryParsedPart1 for S {
+    type ParsedInput = S::ParsedInput;
+
+    fn parse(input: &str) -> ParseResult<Self::ParsedInput> {
+        <S as ParsedPart1>::parse(input)
+    }
+
+    type Part1Output = S::Part1Output;
+
+    fn part1(parsed: &Self::ParsedInput) -> SolveResult<Self::Part1Output> {
+        Ok(<S as ParsedPart1>::part1(parsed))
+    }
+}
+
+impl<S: ParsedPart2> TryParsedPart2 for S {
+    type Part2Output = S::Part2Output;
+
+    fn part2(parsed: &Self::ParsedInput) -> SolveResult<Self::Part2Output> {
+        Ok(<S as ParsedPart2>::part2(parsed))
     }
 }
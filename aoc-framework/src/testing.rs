@@ -0,0 +1,356 @@
+//! Testing of solutions against example inputs and expected answers.
+//!
+//! Examples are data files in a directory per solution: each `*.txt` file is
+//! an example case's input, and `expected.toml` has a table per case (named
+//! by the input's file stem) with the expected answers of parts, written as
+//! strings or integers:
+//!
+//! ```text
+//! tests/examples/day01/
+//! ├── example.txt
+//! └── expected.toml
+//! ```
+//!
+//! ```toml
+//! [example]
+//! part1 = 3
+//! part2 = "6"
+//! ```
+//!
+//! Only the parts with expected answers are run, so a case can cover a
+//! single part. Use the [`test_examples!`][crate::test_examples] macro to
+//! generate a test per solution.
+//!
+//! This module is available with the `testing` feature.
+
+use std::collections::BTreeMap;
+use std::fmt::Display;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+use std::{fs, io};
+
+use thiserror::Error;
+
+use crate::answers::AnswerTable;
+use crate::{
+    OutputHandler, PartSelection, RunError, RunnableSolution, SolutionPart,
+};
+
+/// The name of the file of expected answers in an examples directory.
+pub const EXPECTED_FILE: &str = "expected.toml";
+
+/// An error testing a solution against examples.
+#[derive(Error, Debug)]
+pub enum ExampleError {
+    /// A file or directory of examples couldn't be read.
+    #[error("could not read {}", .path.display())]
+    Io {
+        /// The path that couldn't be read.
+        path: PathBuf,
+        source: io::Error,
+    },
+
+    /// The expected file was not valid TOML of the expected shape.
+    #[error("could not parse {}", .path.display())]
+    Toml {
+        /// The path of the expected file.
+        path: PathBuf,
+        source: toml::de::Error,
+    },
+
+    /// The examples directory had no example inputs.
+    #[error("no example inputs in {}", .path.display())]
+    NoCases {
+        /// The path of the examples directory.
+        path: PathBuf,
+    },
+
+    /// A case had an input without expected answers, or expected answers
+    /// without an input.
+    #[error("example {case:?} needs an input file and expected answers")]
+    Incomplete {
+        /// The name of the case.
+        case: String,
+    },
+
+    /// Running the solution on a case failed.
+    #[error("example {case:?} failed to run")]
+    Run {
+        /// The name of the case.
+        case: String,
+        source: RunError,
+    },
+
+    /// A part expected to be checked output no answer.
+    #[error("example {case:?} {} output no answer", .part.default_name())]
+    NoAnswer {
+        /// The name of the case.
+        case: String,
+        /// The part without an answer.
+        part: SolutionPart,
+    },
+
+    /// A part's answer did not match the expected answer.
+    #[error(
+        "example {case:?} {}: expected {expected}, got {actual}",
+        .part.default_name()
+    )]
+    Mismatch {
+        /// The name of the case.
+        case: String,
+        /// The part with the wrong answer.
+        part: SolutionPart,
+        /// The expected answer.
+        expected: String,
+        /// The actual answer.
+        actual: String,
+    },
+}
+
+/// An example case of input with expected answers.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExampleCase {
+    /// The name of the case.
+    pub name: String,
+    /// The example input.
+    pub input: String,
+    /// The expected answer of part 1, if checked.
+    pub part1: Option<String>,
+    /// The expected answer of part 2, if checked.
+    pub part2: Option<String>,
+}
+
+impl ExampleCase {
+    /// Get the expected answer of a part, if checked.
+    #[must_use]
+    pub fn expected(&self, part: SolutionPart) -> Option<&str> {
+        match part {
+            SolutionPart::Part1 => self.part1.as_deref(),
+            SolutionPart::Part2 => self.part2.as_deref(),
+        }
+    }
+}
+
+/// Read a file to a string, mapping errors to an [`ExampleError::Io`].
+fn read_file(path: &Path) -> Result<String, ExampleError> {
+    fs::read_to_string(path).map_err(|source| ExampleError::Io {
+        path: path.into(),
+        source,
+    })
+}
+
+/// Load the example cases of a directory, sorted by name.
+///
+/// # Errors
+///
+/// If the directory or its files can't be read or parsed, there are no
+/// cases, or a case is missing its input or expected answers, an error is
+/// returned.
+pub fn load_examples(dir: &Path) -> Result<Vec<ExampleCase>, ExampleError> {
+    let expected_path = dir.join(EXPECTED_FILE);
+    let mut tables: BTreeMap<String, AnswerTable> =
+        toml::from_str(&read_file(&expected_path)?).map_err(|source| {
+            ExampleError::Toml {
+                path: expected_path,
+                source,
+            }
+        })?;
+
+    let entries = fs::read_dir(dir).map_err(|source| ExampleError::Io {
+        path: dir.into(),
+        source,
+    })?;
+    let mut cases = Vec::new();
+    for entry in entries {
+        let path = entry
+            .map_err(|source| ExampleError::Io {
+                path: dir.into(),
+                source,
+            })?
+            .path();
+        if path.extension().is_none_or(|extension| extension != "txt") {
+            continue;
+        }
+        let Some(name) = path.file_stem().and_then(|stem| stem.to_str()) else {
+            continue;
+        };
+
+        let table = tables
+            .remove(name)
+            .ok_or_else(|| ExampleError::Incomplete { case: name.into() })?;
+        cases.push(ExampleCase {
+            name: name.into(),
+            input: read_file(&path)?,
+            part1: table.part1.map(String::from),
+            part2: table.part2.map(String::from),
+        });
+    }
+
+    if let Some(case) = tables.into_keys().next() {
+        return Err(ExampleError::Incomplete { case });
+    }
+    if cases.is_empty() {
+        return Err(ExampleError::NoCases { path: dir.into() });
+    }
+    cases.sort_by(|a, b| a.name.cmp(&b.name));
+    Ok(cases)
+}
+
+/// An output handler that ignores all events, for running solutions where
+/// output isn't checked.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct NullHandler;
+
+impl OutputHandler for NullHandler {
+    fn solution_name(&mut self, _name: &str) {}
+
+    fn parse_start(&mut self) {}
+
+    fn parse_end(&mut self) {}
+
+    fn parse_end_timed(&mut self, _duration: Duration) {}
+
+    fn part_start(&mut self, _part: SolutionPart) {}
+
+    fn part_output(&mut self, _part: SolutionPart, _output: &dyn Display) {}
+
+    fn part_output_timed(
+        &mut self,
+        _part: SolutionPart,
+        _output: &dyn Display,
+        _duration: Duration,
+    ) {
+    }
+}
+
+/// An output handler that records the answers of parts.
+#[derive(Default)]
+struct AnswerRecorder {
+    /// The answers output, by part.
+    answers: Vec<(SolutionPart, String)>,
+}
+
+impl OutputHandler for AnswerRecorder {
+    fn solution_name(&mut self, _name: &str) {}
+
+    fn parse_start(&mut self) {}
+
+    fn parse_end(&mut self) {}
+
+    fn parse_end_timed(&mut self, _duration: Duration) {}
+
+    fn part_start(&mut self, _part: SolutionPart) {}
+
+    fn part_output(&mut self, part: SolutionPart, output: &dyn Display) {
+        self.answers.push((part, output.to_string()));
+    }
+
+    fn part_output_timed(
+        &mut self,
+        part: SolutionPart,
+        output: &dyn Display,
+        _duration: Duration,
+    ) {
+        self.part_output(part, output);
+    }
+}
+
+/// Run a solution on an example case, checking the answers of the parts with
+/// expected answers.
+///
+/// Answers are compared as displayed, ignoring surrounding whitespace.
+///
+/// # Errors
+///
+/// If the case has no expected answers, the solution fails to run, or an
+/// answer is missing or doesn't match, an error is returned.
+///
+/// # Examples
+///
+/// ```
+/// use aoc_framework::testing::{ExampleCase, check_example};
+/// use aoc_framework::{
+///     ParseResult, Part1, SolutionName, impl_runnable_solution,
+/// };
+///
+/// struct LineCount;
+/// impl SolutionName for LineCount {
+///     const NAME: &'static str = "Line Count";
+/// }
+/// impl Part1 for LineCount {
+///     type Part1Output = usize;
+///     fn part1(input: &str) -> ParseResult<Self::Part1Output> {
+///         Ok(input.lines().count())
+///     }
+/// }
+/// impl_runnable_solution!(LineCount => Part1);
+///
+/// let mut case = ExampleCase {
+///     name: "example".into(),
+///     input: "a\nb\n".into(),
+///     part1: Some("2".into()),
+///     part2: None,
+/// };
+/// assert!(check_example::<LineCount>(&case).is_ok());
+///
+/// case.part1 = Some("3".into());
+/// assert!(check_example::<LineCount>(&case).is_err());
+/// ```
+pub fn check_example<S: RunnableSolution>(
+    case: &ExampleCase,
+) -> Result<(), ExampleError> {
+    let parts = match (&case.part1, &case.part2) {
+        (Some(_), Some(_)) => PartSelection::All,
+        (Some(_), None) => PartSelection::Only(SolutionPart::Part1),
+        (None, Some(_)) => PartSelection::Only(SolutionPart::Part2),
+        (None, None) => {
+            return Err(ExampleError::Incomplete {
+                case: case.name.clone(),
+            });
+        }
+    };
+
+    let mut recorder = AnswerRecorder::default();
+    S::run_parts(&mut recorder, &case.input, parts, false).map_err(
+        |source| ExampleError::Run {
+            case: case.name.clone(),
+            source,
+        },
+    )?;
+
+    for part in [SolutionPart::Part1, SolutionPart::Part2] {
+        let Some(expected) = case.expected(part) else {
+            continue;
+        };
+        let actual = recorder
+            .answers
+            .iter()
+            .find(|(answer_part, _)| *answer_part == part)
+            .map(|(_, answer)| answer.trim())
+            .ok_or_else(|| ExampleError::NoAnswer {
+                case: case.name.clone(),
+                part,
+            })?;
+        if actual != expected.trim() {
+            return Err(ExampleError::Mismatch {
+                case: case.name.clone(),
+                part,
+                expected: expected.into(),
+                actual: actual.into(),
+            });
+        }
+    }
+    Ok(())
+}
+
+/// Run a solution on every example case of a directory, checking answers.
+///
+/// # Errors
+///
+/// If the examples fail to load or any case fails its check, the first error
+/// is returned.
+pub fn check_examples<S: RunnableSolution>(
+    dir: &Path,
+) -> Result<(), ExampleError> {
+    load_examples(dir)?.iter().try_for_each(check_example::<S>)
+}
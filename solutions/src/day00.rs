@@ -21,14 +21,11 @@ impl ParsedPart1 for Day00 {
 
     fn parse(input: &str) -> ParseResult<Self::ParsedInput> {
         // expect lines of unsigned numbers
-        // - ignore trailing whitespace
-        let numbers: Self::ParsedInput =
-            parse_lines(input.trim_end(), |line| {
-                line.parse::<u32>().map_err(|source| {
-                    ParseError::parse_int_from_str(line, source)
-                })
-            })
-            .collect::<ParseResult<_>>()?;
+        let numbers: Self::ParsedInput = parse_lines(input, |line| {
+            line.parse::<u32>()
+                .map_err(|source| ParseError::parse_int_from_str(line, source))
+        })
+        .collect::<ParseResult<_>>()?;
 
         if numbers.is_empty() {
             Err(ParseError::EmptyInput)
@@ -60,33 +57,14 @@ impl_runnable_solution!(Day00 => ParsedPart2);
 mod tests {
     use std::num::IntErrorKind;
 
-    use super::*;
-
-    const EXAMPLE_INPUT: &str = r"10
-20
-30
-40
-";
-
-    #[test]
-    fn part1_solves_example() -> ParseResult<()> {
-        let parsed = Day00::parse(EXAMPLE_INPUT)?;
-        let result = Day00::part1(&parsed);
-        assert_eq!(result, 4);
-        Ok(())
-    }
+    use aoc_framework::Input;
 
-    #[test]
-    fn part2_solves_example() -> ParseResult<()> {
-        let parsed = Day00::parse(EXAMPLE_INPUT)?;
-        let result = Day00::part2(&parsed);
-        assert_eq!(result, 100);
-        Ok(())
-    }
+    use super::*;
 
     #[test]
     fn parse_error_on_empty_input() {
-        let result = Day00::parse("\n\n");
+        // blank lines are removed by input normalization before parsing
+        let result = Day00::parse(&Input::new("\n\n"));
         assert!(result.is_err(), "expected parse to fail");
         match result.unwrap_err() {
             ParseError::EmptyInput => {}
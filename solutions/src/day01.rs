@@ -146,7 +146,10 @@ impl ParsedPart1 for Day01 {
         let rotations: Self::ParsedInput = parse_lines(input, |line| {
             let first_char: char =
                 line.chars().nth(0).ok_or(ParseError::EmptyLine)?;
-            let direction = Direction::try_from(first_char)?;
+            let direction =
+                Direction::try_from(first_char).map_err(|source| {
+                    ParseError::invalid_column_from_one_based(1, 1, source)
+                })?;
             let distance: RotationDistance =
                 line[1..].parse::<RotationDistance>().map_err(|source| {
                     ParseError::parse_int_from_str(line, source)
@@ -206,30 +209,8 @@ impl_runnable_solution!(Day01 => ParsedPart2);
 
 #[cfg(test)]
 mod tests {
-    use aoc_framework::ParseResult;
-
     use super::*;
 
-    const EXAMPLE_INPUT: &str = r"L68
-L30
-R48
-L5
-R60
-L55
-L1
-L99
-R14
-L82
-";
-
-    #[test]
-    fn part1_solves_example() -> ParseResult<()> {
-        let parsed = Day01::parse(EXAMPLE_INPUT)?;
-        let result = Day01::part1(&parsed);
-        assert_eq!(result, 3);
-        Ok(())
-    }
-
     #[test]
     fn rotate_left_to_zero_does_not_count() {
         let (_, zeros) = rotate_dial_and_count_zeros_passed(
@@ -325,12 +306,4 @@ L82
         );
         assert_eq!(zeros, 1);
     }
-
-    #[test]
-    fn part2_solves_example() -> ParseResult<()> {
-        let parsed = Day01::parse(EXAMPLE_INPUT)?;
-        let result = Day01::part2(&parsed);
-        assert_eq!(result, 6);
-        Ok(())
-    }
 }
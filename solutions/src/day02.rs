@@ -3,6 +3,9 @@ use aoc_framework::{
     impl_runnable_solution,
 };
 
+use crate::util::parse::parse_lines;
+use crate::util::parse::pattern::Pattern;
+
 /// Solution for the second day's puzzle.
 ///
 /// # Input
@@ -106,29 +109,13 @@ impl ParsedPart1 for Day02 {
     type ParsedInput = Vec<(ProductId, ProductId)>;
 
     fn parse(input: &str) -> aoc_framework::ParseResult<Self::ParsedInput> {
-        // need input trimmed to ignore any newline at end
-        let ranges: Self::ParsedInput = input
-            .trim_end()
-            .split(',')
-            .map(|value| {
-                let delimiter = '-';
-                let (first_id_str, second_id_str) = value
-                    .split_once(delimiter)
-                    .ok_or_else(|| ParseError::NoDelimiter(delimiter.into()))?;
-
-                let first_id =
-                    first_id_str.parse::<ProductId>().map_err(|source| {
-                        ParseError::parse_int_from_str(first_id_str, source)
-                    })?;
-
-                let second_id =
-                    second_id_str.parse::<ProductId>().map_err(|source| {
-                        ParseError::parse_int_from_str(second_id_str, source)
-                    })?;
-
-                Ok((first_id, second_id))
-            })
-            .collect::<ParseResult<_>>()?;
+        let pattern = Pattern::new("{u64}-{u64}");
+        let ranges: Self::ParsedInput =
+            parse_lines(input, |line| pattern.parse_separated(line, ","))
+                .collect::<ParseResult<Vec<Vec<_>>>>()?
+                .into_iter()
+                .flatten()
+                .collect();
 
         if ranges.is_empty() {
             Err(ParseError::EmptyInput)
@@ -180,26 +167,6 @@ impl_runnable_solution!(Day02 => ParsedPart2);
 mod tests {
     use super::*;
 
-    const EXAMPLE_INPUT: &str = "11-22,\
-        95-115,\
-        998-1012,\
-        1188511880-1188511890,\
-        222220-222224,\
-        1698522-1698528,\
-        446443-446449,\
-        38593856-38593862,\
-        565653-565659,\
-        824824821-824824827,\
-        2121212118-2121212124\n";
-
-    #[test]
-    fn part1_solves_example() -> ParseResult<()> {
-        let parsed = Day02::parse(EXAMPLE_INPUT)?;
-        let result = Day02::part1(&parsed);
-        assert_eq!(result, 1_227_775_554);
-        Ok(())
-    }
-
     #[test]
     fn is_repeating_sequence_detects_positives() {
         assert!(is_repeating_sequence(11), "false negative on 11");
@@ -217,12 +184,4 @@ mod tests {
             "false positive on 65465465"
         );
     }
-
-    #[test]
-    fn part2_solves_example() -> ParseResult<()> {
-        let parsed = Day02::parse(EXAMPLE_INPUT)?;
-        let result = Day02::part2(&parsed);
-        assert_eq!(result, 4_174_379_265);
-        Ok(())
-    }
 }
@@ -78,14 +78,21 @@ impl ParsedPart1 for Day03 {
                 Err(ParseError::EmptyLine)
             } else {
                 line.chars()
-                    .map(|c| {
+                    .enumerate()
+                    .map(|(index, c)| {
                         c.to_digit(10)
                             .map(|value| {
                                 // should never fail to convert base 10 digit
                                 Joltage::try_from(value)
                                     .expect("failed to convert digit from char into Joltage")
                             })
-                            .ok_or(ParseError::ParseChar(c))
+                            .ok_or_else(|| {
+                                ParseError::invalid_column_from_zero_index(
+                                    index,
+                                    1,
+                                    ParseError::ParseChar(c),
+                                )
+                            })
                     })
                     .collect::<ParseResult<Bank>>()
             }
@@ -178,30 +185,3 @@ impl ParsedPart2 for Day03 {
 }
 
 impl_runnable_solution!(Day03 => ParsedPart2);
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    const EXAMPLE_INPUT: &str = r"987654321111111
-811111111111119
-234234234234278
-818181911112111
-";
-
-    #[test]
-    fn part1_solves_example() -> ParseResult<()> {
-        let parsed = Day03::parse(EXAMPLE_INPUT)?;
-        let result = Day03::part1(&parsed);
-        assert_eq!(result, 357);
-        Ok(())
-    }
-
-    #[test]
-    fn part2_solves_example() -> ParseResult<()> {
-        let parsed = Day03::parse(EXAMPLE_INPUT)?;
-        let result = Day03::part2(&parsed);
-        assert_eq!(result, 3_121_910_778_619);
-        Ok(())
-    }
-}
@@ -2,10 +2,11 @@ use aoc_framework::{
     ParseError, ParseResult, ParsedPart1, ParsedPart2, SolutionName,
     impl_runnable_solution,
 };
-use nalgebra::{DMatrix, Vector2};
+use nalgebra::DMatrix;
 
-use crate::util::matrix::{MatrixPoint, MatrixPointAccess};
+use crate::util::matrix::{MatrixPoint, MatrixPointAccess, Neighborhood};
 use crate::util::parse::parse_grid;
+use crate::util::sparse_grid::SparseGrid;
 
 /// Solution for the fourth day's puzzle.
 ///
@@ -58,32 +59,23 @@ impl TryFrom<char> for GridCell {
 ///
 /// Adjacency can be in cardinal directions or diagonal directions.
 fn count_adjacent_rolls(
-    grid: &DMatrix<GridCell>,
+    grid: &impl MatrixPointAccess<GridCell>,
     target: MatrixPoint,
 ) -> usize {
-    let neighbor_offsets: [Vector2<i32>; 8] = [
-        Vector2::new(1, 0),
-        Vector2::new(1, 1),
-        Vector2::new(0, 1),
-        Vector2::new(-1, 1),
-        Vector2::new(-1, 0),
-        Vector2::new(-1, -1),
-        Vector2::new(0, -1),
-        Vector2::new(1, -1),
-    ];
-
-    // iterate offsets, get neighbor values, check they're a roll
-    neighbor_offsets
-        .iter()
-        .filter(|&offset| {
-            grid.get_at_point(target + offset)
-                .is_some_and(|&neighbor| neighbor == GridCell::Roll)
+    // iterate neighbors, check they're a roll
+    grid.neighbors(target, Neighborhood::Moore)
+        .filter(|&neighbor| {
+            grid.get_at_point(neighbor)
+                .is_some_and(|&cell| cell == GridCell::Roll)
         })
         .count()
 }
 
 /// Check if a position in the grid holds an available roll.
-fn is_available_roll(grid: &DMatrix<GridCell>, target: MatrixPoint) -> bool {
+fn is_available_roll(
+    grid: &impl MatrixPointAccess<GridCell>,
+    target: MatrixPoint,
+) -> bool {
     // expect target is a roll & fewer than 4 adjacent rolls
     grid.get_at_point(target).is_some_and(|&v| {
         v == GridCell::Roll && count_adjacent_rolls(grid, target) < 4
@@ -121,13 +113,15 @@ impl ParsedPart2 for Day04 {
     type Part2Output = usize;
 
     fn part2(grid: &Self::ParsedInput) -> Self::Part2Output {
-        // need grid which we can modify during processing
-        let mut grid = grid.clone();
+        // need grid which we can modify during processing; only storing rolls
+        // means each pass only checks the cells that held rolls
+        let mut grid = SparseGrid::from_matrix(grid, GridCell::Empty);
 
         let mut count: Self::Part2Output = 0;
         loop {
             let available_rolls: Vec<MatrixPoint> = grid
-                .points()
+                .iter()
+                .map(|(point, _)| point)
                 .filter(|&point| is_available_roll(&grid, point))
                 .collect();
 
@@ -137,11 +131,10 @@ impl ParsedPart2 for Day04 {
 
             count += available_rolls.len();
 
-            // remove the available rolls from the grid for next loop
+            // remove the available rolls from the grid for next loop, so
+            // passes only check the rolls left
             for point in available_rolls {
-                if let Some(value_ref) = grid.get_at_point_mut(point) {
-                    *value_ref = GridCell::Empty;
-                }
+                grid.remove(point);
             }
         }
         count
@@ -154,18 +147,6 @@ impl_runnable_solution!(Day04 => ParsedPart2);
 mod tests {
     use super::*;
 
-    const EXAMPLE_INPUT: &str = r"..@@.@@@@.
-@@@.@.@.@@
-@@@@@.@.@@
-@.@@@@..@.
-@@.@@@@.@@
-.@@@@@@@.@
-.@.@.@.@@@
-@.@@@.@@@@
-.@@@@@@@@.
-@.@.@@@.@.
-";
-
     #[test]
     fn count_adjacent_rolls_zero() {
         let matrix: DMatrix<GridCell> = DMatrix::from_row_slice(
@@ -224,20 +205,4 @@ mod tests {
         );
         assert_eq!(count_adjacent_rolls(&matrix_bottom, center_point), 1);
     }
-
-    #[test]
-    fn part1_solves_example() -> ParseResult<()> {
-        let parsed = Day04::parse(EXAMPLE_INPUT)?;
-        let result = Day04::part1(&parsed);
-        assert_eq!(result, 13);
-        Ok(())
-    }
-
-    #[test]
-    fn part2_solves_example() -> ParseResult<()> {
-        let parsed = Day04::parse(EXAMPLE_INPUT)?;
-        let result = Day04::part2(&parsed);
-        assert_eq!(result, 43);
-        Ok(())
-    }
 }
@@ -3,7 +3,7 @@ use aoc_framework::{
     impl_runnable_solution,
 };
 
-use crate::util::parse::{parse_lines, parse_lines_with_offset};
+use crate::util::parse::{parse_chunks, parse_delimited};
 
 /// Solution for the fifth day's puzzle.
 ///
@@ -138,54 +138,21 @@ impl ParsedPart1 for Day05 {
     type ParsedInput = Inventory;
 
     fn parse(input: &str) -> ParseResult<Self::ParsedInput> {
-        // detect windows-style CRLF, fallback to LF
-        let delimiter = if input.contains("\r\n\r\n") {
-            "\r\n\r\n"
-        } else {
-            "\n\n"
-        };
-        let (ranges_input, ids_input) = input
-            .split_once(delimiter)
-            .ok_or_else(|| ParseError::NoChunkDelimiter(delimiter.into()))?;
-
-        if ranges_input.is_empty() {
-            return Err(ParseError::EmptyChunk {
-                chunk_number: 1,
-                description: "fresh ingredient ranges".into(),
-            });
-        }
-        if ids_input.is_empty() {
-            return Err(ParseError::EmptyChunk {
-                chunk_number: 2,
-                description: "available ingredient IDs".into(),
-            });
-        }
-
-        let ranges: Vec<FreshIngredientRange> =
-            parse_lines(ranges_input, |line| {
-                let delimiter = '-';
-                let (first_id_str, second_id_str) = line
-                    .split_once(delimiter)
-                    .ok_or_else(|| ParseError::NoDelimiter(delimiter.into()))?;
-
-                let first_id =
-                    first_id_str.parse::<IngredientId>().map_err(|source| {
-                        ParseError::parse_int_from_str(first_id_str, source)
-                    })?;
-
-                let second_id = second_id_str.parse::<IngredientId>().map_err(
-                    |source| {
-                        ParseError::parse_int_from_str(second_id_str, source)
-                    },
-                )?;
-
+        let [ranges_chunk, ids_chunk] = parse_chunks(
+            input,
+            ["fresh ingredient ranges", "available ingredient IDs"],
+        )?;
+
+        let ranges: Vec<FreshIngredientRange> = ranges_chunk
+            .parse_lines(|line| {
+                let [first_id, second_id] =
+                    parse_delimited::<IngredientId, 2>(line, '-')?;
                 Ok((first_id, second_id))
             })
             .collect::<ParseResult<_>>()?;
 
-        // line offset should be length of ranges plus 1 for empty line
-        let available_ids: Vec<IngredientId> =
-            parse_lines_with_offset(ids_input, ranges.len() + 1, |line| {
+        let available_ids: Vec<IngredientId> = ids_chunk
+            .parse_lines(|line| {
                 line.parse::<IngredientId>().map_err(|source| {
                     ParseError::parse_int_from_str(line, source)
                 })
@@ -245,37 +212,3 @@ impl ParsedPart2 for Day05 {
 }
 
 impl_runnable_solution!(Day05 => ParsedPart2);
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    const EXAMPLE_INPUT: &str = r"3-5
-10-14
-16-20
-12-18
-
-1
-5
-8
-11
-17
-32
-";
-
-    #[test]
-    fn part1_solves_example() -> ParseResult<()> {
-        let parsed = Day05::parse(EXAMPLE_INPUT)?;
-        let result = Day05::part1(&parsed);
-        assert_eq!(result, 3);
-        Ok(())
-    }
-
-    #[test]
-    fn part2_solves_example() -> ParseResult<()> {
-        let parsed = Day05::parse(EXAMPLE_INPUT)?;
-        let result = Day05::part2(&parsed);
-        assert_eq!(result, 14);
-        Ok(())
-    }
-}
@@ -1,9 +1,8 @@
 use aoc_framework::{
     ParseError, ParseResult, Part1, Part2, SolutionName, impl_runnable_solution,
 };
-use nalgebra::DMatrix;
 
-use crate::util::parse::parse_grid;
+use crate::util::parse::{extract_integers, parse_grid};
 
 /// Solution for the sixth day's puzzle.
 ///
@@ -106,75 +105,70 @@ impl Part1 for Day06 {
     type Part1Output = ProblemResult;
 
     fn part1(input: &str) -> ParseResult<Self::Part1Output> {
-        // let's collect parse-able strings, left to right, top to bottom,
-        // whitespace-separated as cells so a matrix can be built from it
-
-        let lines: Vec<&str> = input.lines().collect();
-
-        let rows = lines.len();
-        if rows == 0 {
+        // the last line holds operations, the lines above hold the numbers
+        // of each problem as whitespace separated columns
+        let input = input.trim_end_matches('\n');
+        if input.is_empty() {
             return Err(ParseError::EmptyInput);
         }
+        let (number_lines, operation_line) =
+            input.rsplit_once('\n').unwrap_or(("", input));
+        let number_lines: Vec<&str> = if number_lines.is_empty() {
+            Vec::new()
+        } else {
+            number_lines.lines().collect()
+        };
+        let operation_line_idx = number_lines.len();
+
+        let operations: Vec<Operation> = operation_line
+            .split_whitespace()
+            .map(Operation::try_from)
+            .collect::<ParseResult<_>>()
+            .map_err(|source| {
+                ParseError::invalid_line_from_zero_index(
+                    operation_line_idx,
+                    source,
+                )
+            })?;
+        if operations.is_empty() {
+            return Err(ParseError::invalid_line_from_zero_index(
+                operation_line_idx,
+                ParseError::EmptyLine,
+            ));
+        }
 
-        let cols = lines
-            .first()
-            .map_or(0, |line| line.split_whitespace().count());
-
-        let mut cells: Vec<String> =
-            Vec::with_capacity(rows.saturating_mul(cols));
-
-        for (line_idx, &line) in lines.iter().enumerate() {
-            let line_cells: Vec<&str> = line.split_whitespace().collect();
-            if line_cells.is_empty() {
+        let mut problems: Vec<Problem> = operations
+            .into_iter()
+            .map(|operation| {
+                Problem(Vec::with_capacity(number_lines.len()), operation)
+            })
+            .collect();
+
+        for (line_idx, line) in number_lines.into_iter().enumerate() {
+            let numbers = extract_integers(line).map_err(|source| {
+                ParseError::invalid_line_from_zero_index(line_idx, source)
+            })?;
+            if numbers.is_empty() {
                 return Err(ParseError::invalid_line_from_zero_index(
                     line_idx,
                     ParseError::EmptyLine,
                 ));
             }
-            if line_cells.len() != cols {
+            if numbers.len() != problems.len() {
                 return Err(ParseError::invalid_line_from_zero_index(
                     line_idx,
                     ParseError::LineLength {
-                        expected: cols,
-                        actual: line_cells.len(),
+                        expected: problems.len(),
+                        actual: numbers.len(),
                     },
                 ));
             }
 
-            for cell in line_cells {
-                cells.push(cell.into());
+            for (problem, number) in problems.iter_mut().zip(numbers) {
+                problem.0.push(number);
             }
         }
 
-        let matrix = DMatrix::from_row_iterator(rows, cols, cells);
-
-        // then let's iterate matrix by columns, working across the column to
-        // parse numbers & operation to create problems from
-
-        let mut problems: Vec<Problem> = Vec::with_capacity(cols);
-
-        for column in matrix.column_iter() {
-            let mut numbers: Vec<Number> = Vec::with_capacity(rows - 1);
-            let mut operation_opt: Option<ParseResult<Operation>> = None;
-
-            let mut column_iter = column.iter().peekable();
-            while let Some(cell) = column_iter.next() {
-                if column_iter.peek().is_none() {
-                    // expect operation as last cell
-                    operation_opt = Some(Operation::try_from(cell.as_str()));
-                } else {
-                    // expect number
-                    let num: Number = cell.parse().map_err(|source| {
-                        ParseError::parse_int_from_str(cell, source)
-                    })?;
-                    numbers.push(num);
-                }
-            }
-
-            let operation = operation_opt.expect("operation was not parsed")?;
-            problems.push(Problem(numbers, operation));
-        }
-
         // all that's left is calculating problems and finding sum
         Ok(problems.iter().map(Problem::calculate).sum())
     }
@@ -261,19 +255,6 @@ impl_runnable_solution!(Day06 => Part2);
 mod tests {
     use super::*;
 
-    const EXAMPLE_INPUT: &str = r"123 328  51 64 
- 45 64  387 23 
-  6 98  215 314
-*   +   *   +  
-";
-
-    #[test]
-    fn part1_solves_example() -> ParseResult<()> {
-        let result = Day06::part1(EXAMPLE_INPUT)?;
-        assert_eq!(result, 4_277_556);
-        Ok(())
-    }
-
     #[test]
     fn part2_calculates_individual_problem() -> ParseResult<()> {
         let problem = r"123
@@ -287,9 +268,10 @@ mod tests {
     }
 
     #[test]
-    fn part2_solves_example() -> ParseResult<()> {
-        let result = Day06::part2(EXAMPLE_INPUT)?;
-        assert_eq!(result, 3_263_827);
-        Ok(())
+    fn part1_locates_malformed_cell() {
+        let worksheet = "12  34\n5  x6\n*   +\n";
+        let error = Day06::part1(worksheet).expect_err("expected to fail");
+        let span = error.location().expect("expected a location");
+        assert_eq!((span.line, span.column, span.width), (2, Some(4), 2));
     }
 }
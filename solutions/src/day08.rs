@@ -8,6 +8,8 @@ use nalgebra::Point3;
 use ordered_float::NotNan;
 
 use crate::util::parse::parse_lines;
+use crate::util::parse::pattern::Pattern;
+use crate::util::union_find::DisjointSet;
 
 /// Solution for eighth day's puzzle.
 ///
@@ -44,87 +46,57 @@ impl SolutionName for Day08 {
 /// point number.
 type Dimension = f64;
 
-/// A structure of a distance between a point pair and the points of the pair.
+/// A structure of a distance between a point pair and the indexes of the
+/// pair's points.
 #[derive(Debug, Clone)]
 struct DistancePointPair {
     distance: Dimension,
-    p: Point3<Dimension>,
-    q: Point3<Dimension>,
+    p_idx: usize,
+    q_idx: usize,
 }
 
 impl DistancePointPair {
-    fn new(p: Point3<Dimension>, q: Point3<Dimension>) -> Self {
-        let distance = nalgebra::distance(&p, &q);
-        Self { distance, p, q }
+    fn new(
+        junctions: &[Point3<Dimension>],
+        p_idx: usize,
+        q_idx: usize,
+    ) -> Self {
+        let distance = nalgebra::distance(&junctions[p_idx], &junctions[q_idx]);
+        Self {
+            distance,
+            p_idx,
+            q_idx,
+        }
     }
 }
 
-/// A struct managing a collection of circuit groups.
-#[derive(Default)]
-struct Circuits {
-    /// A collection of groups of circuits, defined as a collection of points
-    /// that connect together.
-    groups: Vec<Vec<Point3<Dimension>>>,
-    // can't use HashSet of points due to floats not implementing Eq
-    // NOTE this isn't including how unconnected points form 1-sized circuits;
-    // seems fine though as its relevant values would have contributed to a
-    // product, which a*1 = a.
-}
+/// A struct managing the circuits formed by connecting junctions, tracking
+/// junctions by index.
+///
+/// An unconnected junction is a circuit of size 1.
+struct Circuits(DisjointSet);
 
 impl Circuits {
-    /// Add a connection which can extend, create, or merge circuit groups.
-    fn add_connection(&mut self, p: Point3<Dimension>, q: Point3<Dimension>) {
-        // need to determine if p and/or q are already in groups
-        let p_idx_search = self.groups.iter().position(|g| g.contains(&p));
-        let q_idx_search = self.groups.iter().position(|g| g.contains(&q));
-
-        match (p_idx_search, q_idx_search) {
-            (Some(p_idx), Some(q_idx)) => {
-                if p_idx != q_idx {
-                    // both in different groups, merge together
-                    // - make sure the index removed is the larger one, or get
-                    //   index shifting errors!
-                    let (keep_idx, remove_idx) = if p_idx < q_idx {
-                        (p_idx, q_idx)
-                    } else {
-                        (q_idx, p_idx)
-                    };
-
-                    let removed_group = self.groups.remove(remove_idx);
-                    self.groups[keep_idx].extend(removed_group);
-                }
-                // else both in same group, no change
-            }
-            (Some(p_idx), None) => {
-                // q not in group, add to p's group
-                self.groups[p_idx].push(q);
-            }
-            (None, Some(q_idx)) => {
-                // p not in group, add to q's group
-                self.groups[q_idx].push(p);
-            }
-            (None, None) => {
-                // neither in groups, create new group
-                self.groups.push(vec![p, q]);
-            }
-        }
+    /// Create circuits of junctions that aren't connected yet.
+    fn new(junction_count: usize) -> Self {
+        Self(DisjointSet::new(junction_count))
     }
 
-    /// Get an ascending sorted vector of circuit group sizes.
+    /// Add a connection between junctions by index, which can merge circuits.
+    fn add_connection(&mut self, p_idx: usize, q_idx: usize) {
+        self.0.union(p_idx, q_idx);
+    }
+
+    /// Get an ascending sorted vector of circuit sizes.
     fn sorted_circuit_sizes(&self) -> Vec<usize> {
-        let mut sizes: Vec<usize> = self.groups.iter().map(Vec::len).collect();
+        let mut sizes: Vec<usize> = self.0.component_sizes().collect();
         sizes.sort_unstable();
         sizes
     }
 
-    /// Get the count of groups of circuits.
+    /// Get the count of circuits.
     fn circuit_count(&self) -> usize {
-        self.groups.len()
-    }
-
-    /// Get the count of points tracked across circuits.
-    fn point_count(&self) -> usize {
-        self.groups.iter().map(Vec::len).sum()
+        self.0.component_count()
     }
 }
 
@@ -134,7 +106,7 @@ fn iterate_pairs(
 ) -> impl Iterator<Item = DistancePointPair> {
     (0..junctions.len()).flat_map(move |i| {
         ((i + 1)..junctions.len())
-            .map(move |j| DistancePointPair::new(junctions[i], junctions[j]))
+            .map(move |j| DistancePointPair::new(junctions, i, j))
     })
 }
 
@@ -173,10 +145,10 @@ fn create_circuits_from_shortest_connections(
     let shortest_pairs: Vec<DistancePointPair> =
         indexes.into_iter().map(|idx| pairs[idx].clone()).collect();
 
-    let mut circuits = Circuits::default();
+    let mut circuits = Circuits::new(junctions.len());
 
     for pair in shortest_pairs {
-        circuits.add_connection(pair.p, pair.q);
+        circuits.add_connection(pair.p_idx, pair.q_idx);
     }
 
     circuits
@@ -200,18 +172,10 @@ impl ParsedPart1 for Day08 {
     type ParsedInput = Vec<Point3<Dimension>>;
 
     fn parse(input: &str) -> ParseResult<Self::ParsedInput> {
+        let pattern = Pattern::new("{f64},{f64},{f64}");
         let junctions: Self::ParsedInput = parse_lines(input, |line| {
-            let mut dimensions = line.splitn(3, ',').map(|d| {
-                d.parse::<Dimension>().map_err(|source| {
-                    ParseError::parse_float_from_str(d, source)
-                })
-            });
-            let x: Dimension =
-                dimensions.next().expect("failed to identify x dimension")?;
-            let y: Dimension =
-                dimensions.next().expect("failed to identify y dimension")?;
-            let z: Dimension =
-                dimensions.next().expect("failed to identify z dimension")?;
+            let (x, y, z): (Dimension, Dimension, Dimension) =
+                pattern.parse(line)?;
             Ok(Point3::new(x, y, z))
         })
         .collect::<ParseResult<_>>()?;
@@ -244,16 +208,14 @@ impl ParsedPart2 for Day08 {
                 .expect("failed to compare distances")
         });
 
-        let mut circuits = Circuits::default();
+        let mut circuits = Circuits::new(junctions.len());
 
         for pair in pairs {
-            circuits.add_connection(pair.p, pair.q);
+            circuits.add_connection(pair.p_idx, pair.q_idx);
 
-            if circuits.circuit_count() == 1
-                && circuits.point_count() == junctions.len()
-            {
+            if circuits.circuit_count() == 1 {
                 // just connected last pair needed
-                return pair.p.x * pair.q.x;
+                return junctions[pair.p_idx].x * junctions[pair.q_idx].x;
             }
         }
         panic!("failed to form single large circuit");
@@ -266,48 +228,14 @@ impl_runnable_solution!(Day08 => ParsedPart2);
 mod tests {
     use super::*;
 
-    const EXAMPLE_INPUT: &str = r"162,817,812
-57,618,57
-906,360,560
-592,479,940
-352,342,300
-466,668,158
-542,29,236
-431,825,988
-739,650,466
-52,470,668
-216,146,977
-819,987,18
-117,168,530
-805,96,715
-346,949,466
-970,615,88
-941,993,340
-862,61,35
-984,92,344
-425,690,689
-";
-
     #[test]
     fn part1_logic_solves_example() -> ParseResult<()> {
-        let parsed = Day08::parse(EXAMPLE_INPUT)?;
+        let parsed =
+            Day08::parse(include_str!("../tests/examples/day08/example.txt"))?;
         let result: <Day08 as ParsedPart1>::Part1Output =
             get_largest_circuit_sizes_from_shortest_connections(&parsed, 10, 3)
                 .product();
         assert_eq!(result, 40);
         Ok(())
     }
-
-    #[test]
-    fn part2_solves_example() -> ParseResult<()> {
-        let parsed = Day08::parse(EXAMPLE_INPUT)?;
-        let result = Day08::part2(&parsed);
-        let expected = 25272.0;
-        let epsilon = 1e-9;
-        assert!(
-            (result - expected).abs() < epsilon,
-            "expected {expected}, got {result}"
-        );
-        Ok(())
-    }
 }
@@ -1,4 +1,4 @@
-use std::collections::{HashSet, VecDeque};
+use std::collections::HashSet;
 
 use aoc_framework::{
     ParseError, ParseResult, ParsedPart1, ParsedPart2, SolutionName,
@@ -6,7 +6,12 @@ use aoc_framework::{
 };
 use nalgebra::{DMatrix, Point2};
 
+use crate::util::graph::flood_fill;
+use crate::util::matrix::{
+    Direction, MatrixPoint, MatrixPointAccess, Neighborhood,
+};
 use crate::util::parse::parse_lines;
+use crate::util::parse::pattern::Pattern;
 
 /// Solution for ninth day's puzzle.
 ///
@@ -45,16 +50,9 @@ impl ParsedPart1 for Day09 {
     type ParsedInput = Vec<Point2<Dimension>>;
 
     fn parse(input: &str) -> ParseResult<Self::ParsedInput> {
+        let pattern = Pattern::new("{u64},{u64}");
         let coords: Self::ParsedInput = parse_lines(input, |line| {
-            let (x_str, y_str) = line
-                .split_once(',')
-                .ok_or(ParseError::NoDelimiter(",".into()))?;
-            let x: Dimension = x_str.parse().map_err(|source| {
-                ParseError::parse_int_from_str(x_str, source)
-            })?;
-            let y: Dimension = y_str.parse().map_err(|source| {
-                ParseError::parse_int_from_str(y_str, source)
-            })?;
+            let (x, y): (Dimension, Dimension) = pattern.parse(line)?;
             Ok(Point2::new(x, y))
         })
         .collect::<ParseResult<_>>()?;
@@ -136,9 +134,6 @@ impl Grid {
     }
 
     fn populate_matrix(&mut self, coords: &[Point2<Dimension>]) {
-        let nrows = self.matrix.nrows();
-        let ncols = self.matrix.ncols();
-
         // make sure we start with `false`
         self.matrix.fill(false);
 
@@ -178,48 +173,36 @@ impl Grid {
 
         // so it seems a flood fill / BFS / DFS is needed to figure out filling
 
-        let mut queue = VecDeque::new();
-        // modify self.matrix to act as a mask of outer cells
-
-        // iterate outer boundary and push `false` cells
-        let outer_boundary = (0..nrows)
-            .flat_map(|row| vec![(row, 0), (row, ncols - 1)])
-            .chain(
-                (1..(ncols - 1))
-                    .flat_map(|col| vec![(0, col), (nrows - 1, col)]),
-            );
-        for index in outer_boundary {
-            if !border_matrix[index] && !self.matrix[index] {
-                queue.push_back(index);
-                self.matrix[index] = true;
-            }
+        // walk the outer boundary clockwise from the origin corner, casting
+        // a ray along each side
+        let mut outer_boundary = vec![MatrixPoint::origin()];
+        let mut direction = Direction::East;
+        for _ in 0..4 {
+            let corner = *outer_boundary
+                .last()
+                .expect("boundary starts with a corner");
+            outer_boundary.extend(border_matrix.cast_ray(corner, direction));
+            direction = direction.turn_right();
         }
 
-        // BFS from outer cells
-        while let Some((row, col)) = queue.pop_front() {
-            let neighbors = [
-                (row.wrapping_sub(1), col),
-                (row + 1, col),
-                (row, col.wrapping_sub(1)),
-                (row, col + 1),
-            ];
-
-            for &(neighbor_row, neighbor_col) in &neighbors {
-                if neighbor_row < nrows
-                    && neighbor_col < ncols
-                    && !border_matrix[(neighbor_row, neighbor_col)]
-                    && !self.matrix[(neighbor_row, neighbor_col)]
-                {
-                    self.matrix[(neighbor_row, neighbor_col)] = true;
-                    queue.push_back((neighbor_row, neighbor_col));
-                }
+        // flood fill from the outer boundary's open cells to find the cells
+        // outside the shape
+        let is_open = |point: &MatrixPoint| {
+            border_matrix.get_at_point(*point) == Some(&false)
+        };
+        let outer_cells =
+            flood_fill(outer_boundary.into_iter().filter(is_open), |&point| {
+                border_matrix
+                    .neighbors(point, Neighborhood::VonNeumann)
+                    .filter(is_open)
+            });
+
+        // cells not outside the shape are within it
+        for point in border_matrix.points() {
+            if let Some(cell_ref) = self.matrix.get_at_point_mut(point) {
+                *cell_ref = !outer_cells.contains(&point);
             }
         }
-
-        // invert mask that was made for filled shape
-        for cell_ref in self.matrix.iter_mut() {
-            *cell_ref = !*cell_ref;
-        }
     }
 
     fn contains_valid_tiles(
@@ -265,34 +248,3 @@ impl ParsedPart2 for Day09 {
 }
 
 impl_runnable_solution!(Day09 => ParsedPart2);
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    const EXAMPLE_INPUT: &str = r"7,1
-11,1
-11,7
-9,7
-9,5
-2,5
-2,3
-7,3
-";
-
-    #[test]
-    fn part1_solves_example() -> ParseResult<()> {
-        let parsed = Day09::parse(EXAMPLE_INPUT)?;
-        let result = Day09::part1(&parsed);
-        assert_eq!(result, 50);
-        Ok(())
-    }
-
-    #[test]
-    fn part2_solves_example() -> ParseResult<()> {
-        let parsed = Day09::parse(EXAMPLE_INPUT)?;
-        let result = Day09::part2(&parsed);
-        assert_eq!(result, 24);
-        Ok(())
-    }
-}
@@ -1,11 +1,14 @@
 use std::collections::HashSet;
 
 use aoc_framework::{
-    ParseError, ParseResult, ParsedPart1, ParsedPart2, SolutionName,
-    impl_runnable_solution,
+    ParseError, ParseResult, SolutionName, SolveError, SolveResult,
+    TryParsedPart1, TryParsedPart2, impl_runnable_solution,
 };
+use nalgebra::{DMatrix, DVector};
 
+use crate::util::linalg::{Gf2, Rational, SystemError, checked_lcm, solve};
 use crate::util::parse::parse_lines;
+use crate::util::parse::pattern::Pattern;
 
 /// Solution for tenth day's puzzle.
 ///
@@ -52,40 +55,9 @@ impl SolutionName for Day10 {
 /// A type for joltage numbers.
 type Joltage = u16;
 
-/// Types of braces used in input.
-enum BraceType {
-    Parentheses,
-    SquareBrackets,
-    CurlyBraces,
-}
-
-/// Strip braces from start & end of string, panic if the braces aren't
-/// available to strip.
-fn strip_braces_panic(s: &str, braces: &BraceType) -> String {
-    match braces {
-        BraceType::Parentheses => {
-            if s.starts_with('(') && s.ends_with(')') {
-                s[1..s.len() - 1].to_string()
-            } else {
-                panic!("string not wrapped with parentheses: {s:?}");
-            }
-        }
-        BraceType::SquareBrackets => {
-            if s.starts_with('[') && s.ends_with(']') {
-                s[1..s.len() - 1].to_string()
-            } else {
-                panic!("string not wrapped with square brackets: {s:?}");
-            }
-        }
-        BraceType::CurlyBraces => {
-            if s.starts_with('{') && s.ends_with('}') {
-                s[1..s.len() - 1].to_string()
-            } else {
-                panic!("string not wrapped with curly braces: {s:?}");
-            }
-        }
-    }
-}
+/// The most free buttons to try every combination of pressing for the light
+/// goal, keeping the search to about a million combinations.
+const MAX_FREE_BUTTONS: usize = 20;
 
 /// A representation of a machine with light indicators & buttons.
 pub struct LightMachine {
@@ -97,90 +69,22 @@ pub struct LightMachine {
     /// the button toggles the lights by index.
     buttons: Vec<HashSet<usize>>,
     /// Joltage requirements for the machine.
-    #[expect(dead_code, reason = "still working on solution")]
     joltage_requirements: Vec<Joltage>,
 }
 
 impl LightMachine {
-    /// Calculate the resulting light configuration (starting all off) after
-    /// pressing the given buttons by index once.
-    fn calculate_resulting_light(
-        &self,
-        button_idxs_pressed: &HashSet<usize>,
-    ) -> Vec<bool> {
-        let mut lights = vec![false; self.light_goal.len()];
-        for &button_idx in button_idxs_pressed {
-            let button = &self.buttons[button_idx];
-            for &light_idx in button {
-                lights[light_idx] = !lights[light_idx];
-            }
-        }
-        lights
-    }
-
-    /// Check pressing the given buttons by index once will match the light
-    /// indicator goal.
-    fn check_light_solution(
-        &self,
-        button_idxs_pressed: &HashSet<usize>,
-    ) -> bool {
-        let result = self.calculate_resulting_light(button_idxs_pressed);
-        result == self.light_goal
-    }
-
-    /// Recursively determine a combination of buttons by index that will match
-    /// the light indicator goal when pressed once.
+    /// Determine the minimum button presses to get the light indicator goal.
     ///
-    /// # Args
-    /// - `presses_left` - how many presses left to apply in this recursion
-    ///   step.
-    /// - `start_idx` - the button index to start at and iterate after when
-    ///   recursing for next step.
-    /// - `current_combo` - the current combination of button indexes being
-    ///   pressed once.
+    /// Returns `None` if no combination of presses gets the goal.
     ///
-    /// # Returns
+    /// # Errors
     ///
-    /// An option that either holds `Some(combo)` for a found working combo, or
-    /// `None` for no working combination found.
-    fn find_button_combinations_for_light(
+    /// If more than [`MAX_FREE_BUTTONS`] buttons are free, so there are too
+    /// many combinations to try, or row reducing overflows, a
+    /// [`SolveError::Overflow`] error is returned.
+    fn find_minimum_button_presses_for_light_goal(
         &self,
-        presses_left: usize,
-        start_idx: usize,
-        current_combo: &mut HashSet<usize>,
-    ) -> Option<HashSet<usize>> {
-        // base case
-        if presses_left == 0 {
-            // either the combo results in the goal or not
-            if self.check_light_solution(current_combo) {
-                return Some(current_combo.clone());
-            }
-            return None;
-        }
-
-        // iterate remaining buttons to press
-        for idx in start_idx..self.buttons.len() {
-            current_combo.insert(idx);
-
-            // recurse with one less press left & start index after current index
-            if let Some(result) = self.find_button_combinations_for_light(
-                presses_left - 1,
-                idx + 1,
-                current_combo,
-            ) {
-                return Some(result);
-            }
-
-            // backtrack for next loop
-            current_combo.remove(&idx);
-        }
-
-        // no successful combination found
-        None
-    }
-
-    // Determine the minimum button presses to get the light indicator goal.
-    fn find_minimum_button_presses_for_light_goal(&self) -> Option<usize> {
+    ) -> SolveResult<Option<usize>> {
         /*
         Thanks Gemini for pointing out I don't need permutations of increasing
         presses to distribute as permutations:
@@ -191,321 +95,390 @@ impl LightMachine {
         zero to one press
         */
 
-        for presses in 1..=self.buttons.len() {
-            let mut current_combo = HashSet::new();
-            if self
-                .find_button_combinations_for_light(
-                    presses,
-                    0,
-                    &mut current_combo,
-                )
-                .is_some()
-            {
-                return Some(presses);
+        // pressing a button toggles lights, like adding 1 modulo 2; so presses
+        // form a linear system over GF(2), and every solution is a particular
+        // one plus some combination of the null space
+        let lights = self.light_goal.len();
+        let a =
+            DMatrix::from_fn(lights, self.buttons.len(), |light, button| {
+                Gf2::from(self.buttons[button].contains(&light))
+            });
+        let b = DVector::from_iterator(
+            lights,
+            self.light_goal.iter().map(|&on| Gf2::from(on)),
+        );
+        let solution = match solve(&a, &b) {
+            Ok(solution) => solution,
+            Err(SystemError::Inconsistent) => return Ok(None),
+            Err(SystemError::Overflow) => {
+                return Err(SolveError::Overflow(
+                    "row reducing light goal".into(),
+                ));
             }
+        };
+
+        // free buttons are few, so try every combination of pressing them
+        let free_buttons = solution.null_space.len();
+        if free_buttons > MAX_FREE_BUTTONS {
+            return Err(SolveError::Overflow(format!(
+                "combinations of {free_buttons} free buttons exceed the limit \
+                 of {MAX_FREE_BUTTONS}"
+            )));
         }
+        let count_presses = |presses: &DVector<Gf2>| {
+            presses.iter().filter(|press| press.value() == 1).count()
+        };
 
-        // failed to find min button presses to produce goal
-        None
+        // step through combinations in Gray code order, where each
+        // combination toggles one free button from the one before
+        let mut presses = solution.particular;
+        let mut fewest = count_presses(&presses);
+        for combination in 1..1usize << free_buttons {
+            let toggled = combination.trailing_zeros() as usize;
+            for (press, &value) in
+                presses.iter_mut().zip(&solution.null_space[toggled])
+            {
+                *press = *press + value;
+            }
+            fewest = fewest.min(count_presses(&presses));
+        }
+        Ok(Some(fewest))
     }
 
-    fn find_minimum_button_presses_for_joltage_requirements(&self) -> u64 {
+    /// Determine the minimum button presses to get the joltage requirements.
+    ///
+    /// Returns `None` if no combination of presses meets the requirements.
+    ///
+    /// # Errors
+    ///
+    /// If solving the presses overflows, a [`SolveError::Overflow`] error is
+    /// returned.
+    fn find_minimum_button_presses_for_joltage_requirements(
+        &self,
+    ) -> SolveResult<Option<u64>> {
         /*
-        I'm stuck on getting anything to work, be performant, or be
-        implementable
-        - couldn't figure out what decomposition to use with nalgebra
-        - Copilot guided me to BFS, then A* but both were very slow for even
-          one machine from input
-        - Copilot then wanted me to use good_lp, but both it and Google AI
-          kept feeding me un-compilable code until I eventually coersed it to
-          something valid, to then be met with it failing to link to a
-          `link.exe`
-        I can't solve this right now
-        */
-        todo!()
+        earlier attempts with SVD, A*, and good_lp were either inexact, too
+        slow, or failed to link a native solver
 
-        /*
-        use good_lp::{
-            Expression, Solution, SolverModel, default_solver, variable,
-            variables,
-        };
+        presses form a linear system: for each counter, the sum of presses of
+        buttons affecting it equals its requirement; row reduction leaves most
+        buttons' presses determined by a few "free" buttons, so only those need
+        searching, bounded by the requirements of the counters they affect
+        */
+        JoltageSystem::new(self)?
+            .map_or(Ok(None), |system| system.minimum_presses())
+    }
+}
 
-        if self.joltage_requirements.is_empty() {
-            // technically no presses needed for no requirements
-            return 0;
-        }
+/// Create an error for arithmetic overflowing while solving joltage
+/// requirements.
+fn joltage_overflow(step: &str) -> SolveError {
+    SolveError::Overflow(format!("{step} solving joltage requirements"))
+}
 
-        let target = &self.joltage_requirements;
-        let n_buttons = self.buttons.len();
+/// An augmented row of integer coefficients, with the constant last.
+type Row = Vec<i64>;
 
-        // create int vars: x[i] as presses of button i
-        let mut vars = variables!();
-        let x_vars: Vec<_> = (0..n_buttons)
-            .map(|_| vars.add(variable().integer().min(0)))
-            .collect();
+/// A linear system of button presses meeting joltage requirements, solved for
+/// pivot buttons in terms of free buttons.
+struct JoltageSystem {
+    /// A row per pivot button, solving for its presses: the pivot's
+    /// coefficient times its presses, plus the free buttons' coefficients
+    /// times their presses, equals the constant.
+    ///
+    /// Rows are scaled to integers by a common denominator, so they are
+    /// exact.
+    rows: Vec<Row>,
+    /// The button index of each row's pivot, by row.
+    pivots: Vec<usize>,
+    /// The indexes of buttons not solved for by a row, which are searched.
+    free_buttons: Vec<usize>,
+    /// The most presses of each button before some counter would exceed its
+    /// requirement, by button index.
+    max_presses: Vec<i64>,
+}
 
-        // objective: minimize sum of x[i]
-        // use default solver
-        let objective: Expression = x_vars.iter().sum();
-        let mut model = vars.minimise(objective).using(default_solver);
-
-        // build constraints: for each counter i, sum of
-        // (button j affects i) * x[j] == target[i]
-        for (i, &target_val) in target.iter().enumerate() {
-            let mut expr: Expression = 0.into();
-            for (j, button) in self.buttons.iter().enumerate() {
-                if button.contains(&i) {
-                    expr += x_vars[j];
-                }
+impl JoltageSystem {
+    /// Build and solve the system for a machine's buttons and joltage
+    /// requirements.
+    ///
+    /// Returns `None` if no presses, even fractional, meet the requirements.
+    ///
+    /// # Errors
+    ///
+    /// If row reducing or scaling rows to integers overflows, a
+    /// [`SolveError::Overflow`] error is returned.
+    fn new(machine: &LightMachine) -> SolveResult<Option<Self>> {
+        let buttons = machine.buttons.len();
+        let counters = machine.joltage_requirements.len();
+
+        // a row per counter, holding which buttons affect it
+        let a = DMatrix::from_fn(counters, buttons, |counter, button| {
+            Rational::from(i64::from(
+                machine.buttons[button].contains(&counter),
+            ))
+        });
+        let b = DVector::from_iterator(
+            counters,
+            machine
+                .joltage_requirements
+                .iter()
+                .map(|&requirement| Rational::from(i64::from(requirement))),
+        );
+        let solution = match solve(&a, &b) {
+            Ok(solution) => solution,
+            Err(SystemError::Inconsistent) => return Ok(None),
+            Err(SystemError::Overflow) => {
+                return Err(joltage_overflow("row reducing"));
             }
-            model = model.with(expr.eq(target_val));
-        }
-
-        // solve
-        model.solve().map_or_else(
-            |_| panic!("ILP solver failed to find solution"),
-            |solution| {
-                x_vars
-                    .iter()
-                    .map(|&var| {
-                        #[expect(clippy::cast_possible_truncation, clippy::cast_sign_loss, reason = "variables have lower bound 0 and shouldn't be aggressively big")]
-                        let value = solution.value(var).round() as u64;
-                        value
-                    })
-                    .sum()
-            },
-        )
-        */
+        };
 
-        /*
-        // prepare button increments: index first by button, then by counter
-        let button_vectors: Vec<Vec<Joltage>> = self
-            .buttons
+        // a pivot's presses are its particular presses, plus its part of each
+        // free button's null space vector times that button's presses
+        let pivots: Vec<usize> = (0..buttons)
+            .filter(|button| !solution.free_variables.contains(button))
+            .collect();
+        let rows = pivots
             .iter()
-            .map(|btn| {
-                (0..n_counters)
-                    .map(|i| Joltage::from(btn.contains(&i)))
-                    .collect()
+            .map(|&pivot| {
+                let particular = solution.particular[pivot];
+                let scale = solution
+                    .null_space
+                    .iter()
+                    .map(|vector| vector[pivot].denominator())
+                    .try_fold(particular.denominator(), checked_lcm)
+                    .ok_or_else(|| joltage_overflow("scaling rows"))?;
+                let scaled = |value: Rational| {
+                    value
+                        .numerator()
+                        .checked_mul(scale / value.denominator())
+                        .ok_or_else(|| joltage_overflow("scaling rows"))
+                };
+
+                let mut row = vec![0; buttons + 1];
+                row[pivot] = scale;
+                row[buttons] = scaled(particular)?;
+                for (vector, &free) in
+                    solution.null_space.iter().zip(&solution.free_variables)
+                {
+                    row[free] = scaled(vector[pivot])?
+                        .checked_neg()
+                        .ok_or_else(|| joltage_overflow("scaling rows"))?;
+                }
+                Ok(row)
             })
-            .collect();
+            .collect::<SolveResult<_>>()?;
 
-        // checking there isn't a counter that can't be affected by a button
-        for (i, &t) in target.iter().enumerate() {
-            assert!(
-                t == 0 || button_vectors.iter().any(|bv| bv[i] != 0),
-                "no button affects counter {i}, impossible to reach requirement"
-            );
-        }
-
-        /*
-        // precompute max number of counters any single button increments
-        let max_button_width = button_vectors
+        let max_presses = machine
+            .buttons
             .iter()
-            .map(|bv| bv.iter().map(|&v| u32::from(v)).sum::<u32>())
-            .max()
-            .unwrap_or(0);
-        assert!(max_button_width > 0, "no usable buttons found");
-        */
-
-        // precompute: for each counter, the min presses to increment by 1
-        let min_presses_per_counter: Vec<u64> = (0..n_counters)
-            .map(|i| {
-                button_vectors
+            .map(|button| {
+                button
                     .iter()
-                    .filter(|bv| bv[i] != 0)
-                    .map(|_| 1u64)
+                    .filter_map(|&counter| {
+                        machine.joltage_requirements.get(counter)
+                    })
                     .min()
-                    .unwrap_or(u64::MAX)
+                    .map_or(0, |&requirement| i64::from(requirement))
             })
             .collect();
 
-        // heuristic for a state: ceil(sum_remaining / max_button_width)
-        let heuristic = |state: &Vec<Joltage>| -> u64 {
-            target
-                .iter()
-                .zip(state.iter())
-                .zip(min_presses_per_counter.iter())
-                .map(|((&t, &s), &min_press)| {
-                    u64::from(t-s) * min_press
-                })
-                .sum()
-            /*
-            let sum_remaining: u32 = target
-                .iter()
-                .zip(state.iter())
-                .map(|(t, s)| u32::from(t - s))
-                .sum();
-            if sum_remaining == 0 {
-                0
-            } else {
-                u64::from(sum_remaining.div_ceil(max_button_width))
-            }
-            */
-        };
-
-        // start search with all 0's
-        let start = vec![0u16; n_counters];
-        if start == target {
-            return 0;
-        }
-
-        //let mut queue = VecDeque::new();
-        //let mut seen = HashSet::new();
-        //queue.push_back((start.clone(), 0));
-        //seen.insert(start);
+        Ok(Some(Self {
+            rows,
+            pivots,
+            free_buttons: solution.free_variables,
+            max_presses,
+        }))
+    }
 
-        // A* priority queue: Reverse((priority, g, state)) so smallest
-        // priority first
-        let mut heap = BinaryHeap::new();
-        let mut best_g = HashMap::new();
+    /// Find the minimum total presses solving the system.
+    ///
+    /// # Errors
+    ///
+    /// If totaling presses overflows, a [`SolveError::Overflow`] error is
+    /// returned.
+    fn minimum_presses(&self) -> SolveResult<Option<u64>> {
+        let mut free_presses = vec![0; self.free_buttons.len()];
+        let mut best = None;
+        self.search_free_presses(0, &mut free_presses, &mut best)?;
+        Ok(best.and_then(|best| u64::try_from(best).ok()))
+    }
 
-        let start_h = heuristic(&start);
-        heap.push(Reverse((start_h, 0u64, start.clone())));
-        best_g.insert(start, 0);
+    /// Recursively assign presses to free buttons from an index onward,
+    /// tracking the best total presses of solutions found.
+    ///
+    /// # Errors
+    ///
+    /// If totaling presses overflows, a [`SolveError::Overflow`] error is
+    /// returned.
+    fn search_free_presses(
+        &self,
+        free_idx: usize,
+        free_presses: &mut [i64],
+        best: &mut Option<i64>,
+    ) -> SolveResult<()> {
+        let free_total = checked_sum(free_presses[..free_idx].iter().copied())
+            .ok_or_else(|| joltage_overflow("totaling free presses"))?;
+        // presses are never negative, so a total can only grow from here
+        if best.is_some_and(|best| free_total >= best)
+            || !self.pivots_in_bounds(free_idx, free_presses)?
+        {
+            return Ok(());
+        }
 
-        while let Some(Reverse((_, g, state))) = heap.pop() {
-            // skip any worse g than best known
-            if let Some(&best) = best_g.get(&state) && g > best {
-                continue;
+        let Some(&button) = self.free_buttons.get(free_idx) else {
+            if let Some(total) = self.solve_pivot_presses(free_presses)?
+                && best.is_none_or(|best| total < best)
+            {
+                *best = Some(total);
             }
+            return Ok(());
+        };
 
-            // expand neighbors by pressing each button once
-            for bv in &button_vectors {
-                // calc next state and prune if any component would exceed
-                // target
-                let mut next = state.clone();
-                let mut ok = true;
-                for i in 0..n_counters {
-                    let sum = next[i].saturating_add(bv[i]);
-                    if sum > target[i] {
-                        ok = false;
-                        break;
-                    }
-                    next[i] = sum;
-                }
-                if !ok {
-                    continue;
-                }
-
-                let next_g = g+1;
-                if let Some(&existing_g) = best_g.get(&next) && next_g >= existing_g {
-                    continue;
-                }
-
-                // check if target reached
-                if next == target {
-                    return next_g;
-                }
+        for presses in 0..=self.max_presses[button] {
+            free_presses[free_idx] = presses;
+            self.search_free_presses(free_idx + 1, free_presses, best)?;
+        }
+        free_presses[free_idx] = 0;
+        Ok(())
+    }
 
-                best_g.insert(next.clone(), next_g);
-                let h = heuristic(&next);
-                let priority = next_g + h;
-                heap.push(Reverse((priority, next_g, next)));
+    /// Check every pivot button could still get presses within its bounds,
+    /// whatever presses the free buttons from an index onward get.
+    ///
+    /// # Errors
+    ///
+    /// If bounding presses overflows, a [`SolveError::Overflow`] error is
+    /// returned.
+    fn pivots_in_bounds(
+        &self,
+        free_idx: usize,
+        free_presses: &[i64],
+    ) -> SolveResult<bool> {
+        let overflow = || joltage_overflow("bounding pivot presses");
+        let constant_col = self.max_presses.len();
+        for (row, &pivot) in self.rows.iter().zip(&self.pivots) {
+            let assigned = self.free_buttons[..free_idx]
+                .iter()
+                .zip(free_presses)
+                .map(|(&button, &presses)| row[button].checked_mul(presses))
+                .try_fold(0, |sum: i64, term| sum.checked_add(term?))
+                .ok_or_else(overflow)?;
+            let (rest_min, rest_max) = self.free_buttons[free_idx..]
+                .iter()
+                .map(|&button| {
+                    row[button].checked_mul(self.max_presses[button])
+                })
+                .try_fold((0, 0), |(min, max): (i64, i64), reach| {
+                    let reach = reach?;
+                    Some((
+                        min.checked_add(reach.min(0))?,
+                        max.checked_add(reach.max(0))?,
+                    ))
+                })
+                .ok_or_else(overflow)?;
+
+            // pivot presses are what's left of the constant, divided by the
+            // positive pivot coefficient
+            let constant = row[constant_col];
+            let pivot_max = row[pivot]
+                .checked_mul(self.max_presses[pivot])
+                .ok_or_else(overflow)?;
+            let lowest = assigned.checked_add(rest_min).ok_or_else(overflow)?;
+            let highest =
+                assigned.checked_add(rest_max).ok_or_else(overflow)?;
+            let least_needed =
+                constant.checked_sub(pivot_max).ok_or_else(overflow)?;
+            if lowest > constant || highest < least_needed {
+                return Ok(false);
             }
         }
+        Ok(true)
+    }
 
-        panic!("failed to find a solution for joltage requirements");
-
-        /*
-        // format buttons & requirements to matrices to solve as linear system:
-        // Ax = b
-
-        // a button will inform on a column of matrix A
-        // - build column major slice
-        let mut a_column_major = Vec::new();
-        for button in self.buttons.iter().by_ref() {
-            // want columns of 1's & 0's; if button affects counter (which will
-            // map to row) then track 1
-            for counter_idx in 0..self.joltage_requirements.len() {
-                let factor = f64::from(button.contains(&counter_idx));
-                a_column_major.push(factor);
+    /// Solve the presses of pivot buttons given presses of free buttons,
+    /// returning the total presses if every button gets a whole,
+    /// non-negative number of presses.
+    ///
+    /// # Errors
+    ///
+    /// If solving presses overflows, a [`SolveError::Overflow`] error is
+    /// returned.
+    fn solve_pivot_presses(
+        &self,
+        free_presses: &[i64],
+    ) -> SolveResult<Option<i64>> {
+        let overflow = || joltage_overflow("solving pivot presses");
+        let constant_col = self.max_presses.len();
+        let mut total =
+            checked_sum(free_presses.iter().copied()).ok_or_else(overflow)?;
+
+        for (row, &pivot) in self.rows.iter().zip(&self.pivots) {
+            let mut remaining = row[constant_col];
+            for (&button, &presses) in
+                self.free_buttons.iter().zip(free_presses)
+            {
+                remaining = row[button]
+                    .checked_mul(presses)
+                    .and_then(|term| remaining.checked_sub(term))
+                    .ok_or_else(overflow)?;
             }
+            if remaining < 0 || remaining % row[pivot] != 0 {
+                return Ok(None);
+            }
+            total = total
+                .checked_add(remaining / row[pivot])
+                .ok_or_else(overflow)?;
         }
-        let a_matrix: DMatrix<f64> = DMatrix::from_column_slice(
-            self.joltage_requirements.len(),
-            self.buttons.len(),
-            &a_column_major,
-        );
 
-        // matrix b will be a column of requirements
-        let b_floats: Vec<_> = self
-            .joltage_requirements
-            .iter()
-            .map(|&j| f64::from(j))
-            .collect();
-        let b_vector: DVector<f64> = DVector::from_column_slice(&b_floats);
-
-        // BUG matrix A can be not-square, example has a case wider than tall
-        let svd = a_matrix.svd();
-        let x_vector = svd.solve(&b_vector).expect("failed to solve system");
-        let eps = 1e-9f64;
-        x_vector
-            .iter()
-            .map(|&x| {
-                assert!(!x.is_nan(), "solution contains NaN");
-                let rounded = x.round();
-                assert!(
-                    (x - rounded).abs() <= eps,
-                    "solution value not whole number: {x}"
-                );
-                assert!(rounded >= 0.0, "solution value is negative: {x}");
-                assert!(
-                    rounded <= (u64::MAX as f64),
-                    "solution value overflows u64: {x}"
-                );
-                rounded as u64
-            })
-            .try_fold(0u64, u64::checked_add)
-            .expect("overflow occurred when summing solution vector")
-        */
-        */
+        Ok(Some(total))
     }
 }
 
-impl ParsedPart1 for Day10 {
+/// Sum numbers, returning `None` on overflow.
+fn checked_sum(numbers: impl IntoIterator<Item = i64>) -> Option<i64> {
+    numbers
+        .into_iter()
+        .try_fold(0, |sum: i64, number| sum.checked_add(number))
+}
+
+impl TryParsedPart1 for Day10 {
     type ParsedInput = Vec<LightMachine>;
 
     fn parse(input: &str) -> aoc_framework::ParseResult<Self::ParsedInput> {
+        let pattern = Pattern::new("[{str}] {str} {{{list(u16, ',')}}}");
+        let button_pattern = Pattern::new("({list(usize, ',')})");
         let machines: Self::ParsedInput = parse_lines(input, |line| {
-            let tokens: Vec<&str> = line.split_whitespace().collect();
-            assert!(
-                tokens.len() >= 3,
-                "expected at least 3 tokens across line: {tokens:?}"
-            );
-
-            let light_goal = strip_braces_panic(
-                tokens.first().expect("failed to get first token"),
-                &BraceType::SquareBrackets,
-            )
-            .chars()
-            .map(|c| c == '#')
-            .collect();
-
-            let buttons = tokens[1..tokens.len() - 1]
-                .iter()
-                .map(|button_wiring| {
-                    strip_braces_panic(button_wiring, &BraceType::Parentheses)
-                        .split(',')
-                        .map(|index| {
-                            index.parse().map_err(|source| {
-                                ParseError::parse_int_from_str(index, source)
-                            })
-                        })
-                        .collect::<ParseResult<_>>()
-                })
-                .collect::<ParseResult<_>>()?;
-
-            let joltage_requirements = strip_braces_panic(
-                tokens.last().expect("failed to get last token"),
-                &BraceType::CurlyBraces,
-            )
-            .split(',')
-            .map(|number| {
-                number.parse().map_err(|source| {
-                    ParseError::parse_int_from_str(number, source)
-                })
-            })
-            .collect::<ParseResult<_>>()?;
+            let captures = pattern.captures(line)?;
+
+            let light_goal =
+                captures.get::<&str>(0)?.chars().map(|c| c == '#').collect();
+            let joltage_requirements: Vec<Joltage> = captures.get(2)?;
+
+            // buttons index the counters, so indexes must be within them
+            let counters = joltage_requirements.len();
+            let buttons = captures.field(1)?.parse_with(|wirings| {
+                let buttons: Vec<(Vec<usize>,)> =
+                    button_pattern.parse_separated(wirings, " ")?;
+                buttons
+                    .into_iter()
+                    .enumerate()
+                    .map(|(button_idx, (indexes,))| {
+                        match indexes.iter().find(|&&index| index >= counters) {
+                            Some(&index) => {
+                                Err(ParseError::invalid_field_from_zero_index(
+                                    button_idx,
+                                    ParseError::IndexOutOfRange {
+                                        index,
+                                        len: counters,
+                                    },
+                                ))
+                            }
+                            None => Ok(indexes.into_iter().collect()),
+                        }
+                    })
+                    .collect::<ParseResult<Vec<HashSet<usize>>>>()
+            })?;
 
             Ok(LightMachine {
                 light_goal,
@@ -524,68 +497,111 @@ impl ParsedPart1 for Day10 {
 
     type Part1Output = u32;
 
-    fn part1(machines: &Self::ParsedInput) -> Self::Part1Output {
+    fn part1(machines: &Self::ParsedInput) -> SolveResult<Self::Part1Output> {
         machines
             .iter()
-            .map(|machine| {
-                machine.find_minimum_button_presses_for_light_goal().expect(
-                    "failed to find minimum button presses for a machine",
-                )
-            })
-            .try_fold(0u32, |acc, v| {
-                acc.checked_add(v.try_into().expect(
-                    "failed to cast a minimum button press for summing",
-                ))
+            .enumerate()
+            .try_fold(0u32, |acc, (index, machine)| {
+                let presses = machine
+                    .find_minimum_button_presses_for_light_goal()?
+                    .ok_or_else(|| {
+                        SolveError::NoSolution(format!(
+                            "no button presses match lights of machine {}",
+                            index + 1
+                        ))
+                    })?;
+                let presses = u32::try_from(presses).map_err(|_| {
+                    SolveError::Overflow(
+                        "casting minimum button presses".into(),
+                    )
+                })?;
+                acc.checked_add(presses).ok_or_else(|| {
+                    SolveError::Overflow(
+                        "summing minimum button presses".into(),
+                    )
+                })
             })
-            .expect("overflow occurred when summing")
     }
 }
 
-impl ParsedPart2 for Day10 {
+impl TryParsedPart2 for Day10 {
     type Part2Output = u64;
 
-    #[expect(clippy::print_stdout, reason = "debugging")]
-    fn part2(machines: &Self::ParsedInput) -> Self::Part2Output {
+    fn part2(machines: &Self::ParsedInput) -> SolveResult<Self::Part2Output> {
         machines
             .iter()
-            .map(|machine| {
-                println!("going to press buttons...");
-                let result = machine
-                    .find_minimum_button_presses_for_joltage_requirements();
-                println!("pressed buttons {result} times");
-                result
+            .enumerate()
+            .try_fold(0u64, |acc, (index, machine)| {
+                let presses = machine
+                    .find_minimum_button_presses_for_joltage_requirements()?
+                    .ok_or_else(|| {
+                        SolveError::NoSolution(format!(
+                            "no button presses match joltage requirements of machine {}",
+                            index + 1
+                        ))
+                    })?;
+                acc.checked_add(presses).ok_or_else(|| {
+                    SolveError::Overflow(
+                        "summing minimum button presses".into(),
+                    )
+                })
             })
-            .try_fold(0u64, u64::checked_add)
-            .expect("overflow occurred when summing")
     }
 }
 
-// TODO still working on part 2
-impl_runnable_solution!(Day10 => ParsedPart1);
+impl_runnable_solution!(Day10 => TryParsedPart2);
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    const EXAMPLE_INPUT: &str = r"[.##.] (3) (1,3) (2) (2,3) (0,2) (0,1) {3,5,4,7}
-[...#.] (0,2,3,4) (2,3) (0,4) (0,1,2) (1,2,3,4) {7,5,12,7,2}
-[.###.#] (0,1,2,3,4) (0,3,4) (0,1,2,4,5) (1,2) {10,11,11,5,10,5}
-";
+    #[test]
+    fn part1_tries_combinations_up_to_free_button_limit() {
+        // buttons toggling no lights are all free
+        let machine = LightMachine {
+            light_goal: vec![false],
+            buttons: vec![HashSet::new(); MAX_FREE_BUTTONS],
+            joltage_requirements: vec![0],
+        };
+        let result = Day10::part1(&vec![machine]);
+        assert!(matches!(result, Ok(0)), "got result: {result:?}");
+    }
 
     #[test]
-    fn part1_solves_example() -> ParseResult<()> {
-        let parsed = Day10::parse(EXAMPLE_INPUT)?;
-        let result = Day10::part1(&parsed);
-        assert_eq!(result, 7);
-        Ok(())
+    fn part1_errors_past_free_button_limit() {
+        let machine = LightMachine {
+            light_goal: vec![false],
+            buttons: vec![HashSet::new(); MAX_FREE_BUTTONS + 1],
+            joltage_requirements: vec![0],
+        };
+        let result = Day10::part1(&vec![machine]);
+        assert!(
+            matches!(result, Err(SolveError::Overflow(_))),
+            "got result: {result:?}"
+        );
     }
 
-    #[ignore = "still working on solution"]
     #[test]
-    fn part2_solves_example() -> ParseResult<()> {
-        let parsed = Day10::parse(EXAMPLE_INPUT)?;
-        let result = Day10::part2(&parsed);
-        assert_eq!(result, 33);
-        Ok(())
+    fn parse_errors_on_button_past_counters() {
+        let Err(error) = Day10::parse("[.#] (0) (0,2) {3,4}\n") else {
+            panic!("expected parse to fail");
+        };
+        let span = error.location().expect("expected a location");
+        assert_eq!((span.line, span.column, span.width), (1, Some(6), 9));
+    }
+
+    #[test]
+    fn part2_errors_on_overflowing_presses() {
+        let system = JoltageSystem {
+            rows: vec![vec![1, i64::MAX, 0]],
+            pivots: vec![0],
+            free_buttons: vec![1],
+            max_presses: vec![1, 2],
+        };
+        let result = system.minimum_presses();
+        assert!(
+            matches!(result, Err(SolveError::Overflow(_))),
+            "got result: {result:?}"
+        );
     }
 }
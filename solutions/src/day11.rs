@@ -1,11 +1,13 @@
-use std::collections::{HashMap, HashSet};
+use std::collections::HashMap;
 
 use aoc_framework::{
-    ParseError, ParseResult, ParsedPart1, ParsedPart2, SolutionName,
-    impl_runnable_solution,
+    ParseResult, SolutionName, SolveError, SolveResult, TryParsedPart1,
+    TryParsedPart2, impl_runnable_solution,
 };
 
+use crate::util::graph::{PathCountError, count_paths};
 use crate::util::parse::parse_lines;
+use crate::util::parse::pattern::Pattern;
 
 /// Solution for eleventh day's puzzle.
 ///
@@ -35,255 +37,124 @@ impl SolutionName for Day11 {
     const NAME: &'static str = "Day 11: Reactor";
 }
 
-type Connections = HashMap<String, HashMap<String, u64>>;
+/// Outputs of each device, by device name.
+type Connections = HashMap<String, Vec<String>>;
 
-fn count_paths(connections: &Connections, start: &str, end: &str) -> u64 {
-    fn dfs_count_paths_recursive(
-        connections: &Connections,
-        current: (&String, &u64),
-        end_node: &str,
-        visited: &mut HashSet<String>,
-        count: &mut u64,
-    ) {
-        visited.insert(current.0.clone());
-
-        // base case
-        if current.0 == end_node {
-            *count += current.1;
-            // backtrack the node visit
-            visited.remove(current.0);
-            return;
-        }
+/// Get the outputs of a device.
+///
+/// Devices without an outputs line have no outputs.
+fn outputs<'a>(
+    connections: &'a Connections,
+    device: &str,
+) -> impl Iterator<Item = &'a str> {
+    connections
+        .get(device)
+        .into_iter()
+        .flatten()
+        .map(String::as_str)
+}
 
-        // explore outputs of current node
-        if let Some(outputs) = connections.get(current.0) {
-            for next in outputs {
-                if !visited.contains(next.0.as_str()) {
-                    // recurse with next as current node
-                    dfs_count_paths_recursive(
-                        connections,
-                        next,
-                        end_node,
-                        visited,
-                        count,
-                    );
-                }
-            }
-        } else {
-            panic!("failed to get outputs of current node: {current:?}");
+/// Count the paths from one device to another by following outputs.
+///
+/// Devices without an outputs line have no outputs, so paths through them
+/// don't count.
+///
+/// # Errors
+///
+/// If outputs loop back to a device, paths could be endless, so a
+/// [`SolveError::NoSolution`] error is returned. If the count overflows, a
+/// [`SolveError::Overflow`] error is returned.
+fn count_paths_between(
+    connections: &Connections,
+    from: &str,
+    to: &str,
+) -> SolveResult<u64> {
+    count_paths(
+        from,
+        |&device| outputs(connections, device),
+        |&device| device == to,
+    )
+    .map_err(|error| match error {
+        PathCountError::Cycle => SolveError::NoSolution(format!(
+            "outputs loop on paths from {from} to {to}"
+        )),
+        PathCountError::Overflow => {
+            SolveError::Overflow(format!("counting paths from {from} to {to}"))
         }
-
-        // backtrack node visited
-        visited.remove(current.0);
-    }
-
-    let mut count = 0;
-    let mut visited = HashSet::new();
-
-    dfs_count_paths_recursive(
-        connections,
-        (&start.to_string(), &1),
-        end,
-        &mut visited,
-        &mut count,
-    );
-    count
+    })
 }
 
-impl ParsedPart1 for Day11 {
+impl TryParsedPart1 for Day11 {
     type ParsedInput = Connections;
 
     fn parse(input: &str) -> ParseResult<Self::ParsedInput> {
-        let mut connections: Self::ParsedInput = parse_lines(input, |line| {
-            let (name, raw_outputs) = line
-                .split_once(':')
-                .ok_or_else(|| ParseError::NoDelimiter(':'.into()))?;
-            assert!(!name.is_empty(), "no device name found before \":\"");
-            let outputs: HashMap<String, u64> = raw_outputs
-                .split_whitespace()
-                .map(|s| (s.to_string(), 1))
-                .collect();
-            assert!(
-                !outputs.is_empty(),
-                "no output connections found after \":\""
-            );
+        let pattern = Pattern::new("{str}: {list(str, ' ')}");
+        parse_lines(input, |line| {
+            let (name, outputs): (&str, Vec<&str>) = pattern.parse(line)?;
+            let outputs = outputs.into_iter().map(String::from).collect();
             Ok((name.to_string(), outputs))
         })
-        .collect::<ParseResult<_>>()?;
-
-        // friend pitched squashing nodes, as there's a good number of
-        // connections to exactly one node
-        // - specifically squashing as a map of an output node and the number
-        //   of paths to it without traveling through other output nodes
-        let squash_candidates: HashSet<String> = connections
-            .iter()
-            .filter(|(name, _)| {
-                // don't squash special names needed for parts
-                !matches!(name.as_str(), "you" | "svr" | "dac" | "fft")
-            })
-            .map(|(name, _)| name.clone())
-            .collect();
-        for name in squash_candidates.iter().by_ref() {
-            if let Some(name_outputs) = connections.get(name).cloned() {
-                for outputs in connections.values_mut() {
-                    // swap any matching name in outputs with name's outputs
-                    if let Some(count) = outputs.get(name).copied() {
-                        for (name_out, name_count) in &name_outputs {
-                            *outputs.entry(name_out.clone()).or_insert(0) +=
-                                name_count * count;
-                        }
-                        outputs.remove(name);
-                    }
-                }
-            } else {
-                panic!(
-                    "failed to find outputs of name in squash candidates: {name:?}"
-                );
-            }
-            connections.remove(name);
-        }
-
-        Ok(connections)
+        .collect()
     }
 
     type Part1Output = u64;
 
-    fn part1(connections: &Self::ParsedInput) -> Self::Part1Output {
-        count_paths(connections, "you", "out")
+    fn part1(
+        connections: &Self::ParsedInput,
+    ) -> SolveResult<Self::Part1Output> {
+        count_paths_between(connections, "you", "out")
     }
 }
 
-fn count_paths_with_required_visits(
-    connections: &Connections,
-    start: &str,
-    end: &str,
-    required_visits: &HashSet<String>,
-) -> u64 {
-    fn dfs_count_paths_recursive(
-        connections: &Connections,
-        current: (&String, &u64),
-        end_node: &str,
-        required_visits: &HashSet<String>,
-        visited: &mut HashMap<String, u64>,
-        count: &mut u64,
-    ) {
-        visited.insert(current.0.clone(), *current.1);
-
-        // base case
-        if current.0 == end_node {
-            // only count if we visited what was required
-            if required_visits
-                .iter()
-                .all(|name| visited.contains_key(name))
-            {
-                // factor counts of the path traveled as multiplicative
-                *count += visited.values().product::<u64>();
-            }
-            // backtrack the node visit
-            visited.remove(current.0);
-            return;
-        }
-
-        // explore outputs of current node
-        if let Some(outputs) = connections.get(current.0) {
-            for next in outputs {
-                if !visited.contains_key(next.0.as_str()) {
-                    // recurse with next as current node
-                    dfs_count_paths_recursive(
-                        connections,
-                        next,
-                        end_node,
-                        required_visits,
-                        visited,
-                        count,
-                    );
-                }
-            }
-        } else {
-            panic!("failed to get outputs of current node: {current:?}");
-        }
-
-        // backtrack node visited
-        visited.remove(current.0);
-    }
-
-    let mut count = 0;
-    let mut visited = HashMap::new();
-
-    dfs_count_paths_recursive(
-        connections,
-        (&start.to_string(), &1),
-        end,
-        required_visits,
-        &mut visited,
-        &mut count,
-    );
-    count
-}
-
-impl ParsedPart2 for Day11 {
+impl TryParsedPart2 for Day11 {
     type Part2Output = u64;
 
-    fn part2(connections: &Self::ParsedInput) -> Self::Part2Output {
-        // NOTE many more possible paths to calculate starting from "svr"
-        // compared to "you", so needed to optimize connections
-        let required_visits =
-            HashSet::from(["dac".to_string(), "fft".to_string()]);
-        count_paths_with_required_visits(
-            connections,
-            "svr",
-            "out",
-            &required_visits,
-        )
+    fn part2(
+        connections: &Self::ParsedInput,
+    ) -> SolveResult<Self::Part2Output> {
+        // without loops, at most one of "dac" & "fft" reaches the other, so
+        // paths visit them in that order
+        let dac_reaches_fft =
+            count_paths_between(connections, "dac", "fft")? > 0;
+        let order = if dac_reaches_fft {
+            ["svr", "dac", "fft", "out"]
+        } else {
+            ["svr", "fft", "dac", "out"]
+        };
+
+        // a path's segments between them are independent, so counts of
+        // segments multiply
+        order.windows(2).try_fold(1, |product: u64, pair| {
+            let segment = count_paths_between(connections, pair[0], pair[1])?;
+            product.checked_mul(segment).ok_or_else(|| {
+                SolveError::Overflow("multiplying path segments".into())
+            })
+        })
     }
 }
 
-impl_runnable_solution!(Day11 => ParsedPart2);
+impl_runnable_solution!(Day11 => TryParsedPart2);
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    const EXAMPLE_INPUT_1: &str = r"aaa: you hhh
-you: bbb ccc
-bbb: ddd eee
-ccc: ddd eee fff
-ddd: ggg
-eee: out
-fff: out
-ggg: out
-hhh: ccc fff iii
-iii: out
-";
-
     #[test]
-    fn part1_solves_example() -> ParseResult<()> {
-        let parsed = Day11::parse(EXAMPLE_INPUT_1)?;
-        let result = Day11::part1(&parsed);
-        assert_eq!(result, 5);
+    fn part1_skips_devices_without_outputs() -> SolveResult<()> {
+        // "dead" has no outputs line, so paths through it end there
+        let connections = Day11::parse("you: dead out\n")?;
+        assert_eq!(Day11::part1(&connections)?, 1);
         Ok(())
     }
 
-    const EXAMPLE_INPUT_2: &str = r"svr: aaa bbb
-aaa: fft
-fft: ccc
-bbb: tty
-tty: ccc
-ccc: ddd eee
-ddd: hub
-hub: fff
-eee: dac
-dac: fff
-fff: ggg hhh
-ggg: out
-hhh: out
-";
-
     #[test]
-    fn part2_solves_example() -> ParseResult<()> {
-        let parsed = Day11::parse(EXAMPLE_INPUT_2)?;
-        let result = Day11::part2(&parsed);
-        assert_eq!(result, 2);
+    fn part1_errors_on_looping_outputs() -> ParseResult<()> {
+        let connections = Day11::parse("you: a\na: you out\n")?;
+        let result = Day11::part1(&connections);
+        assert!(
+            matches!(result, Err(SolveError::NoSolution(_))),
+            "got result: {result:?}"
+        );
         Ok(())
     }
 }
@@ -1,12 +1,12 @@
 //! Solutions implemented for Advent of Code 2025.
 //!
-//! This module provides [`run_day`] to dynamically run a solution by its day.
+//! This module provides [`run_day`] to dynamically run a solution by its day,
+//! looking it up in the [`SOLUTIONS`] registry.
 //!
 //! Making a solution available to run requires implementing
-//! [`RunnableSolution`] (likely via the
+//! [`RunnableSolution`][aoc_framework::RunnableSolution] (likely via the
 //! [`impl_runnable_solution!`][aoc_framework::impl_runnable_solution] macro),
-//! exporting its module, and adding a match case for its day within
-//! [`run_day`].
+//! exporting its module, and adding an entry for its day to [`SOLUTIONS`].
 
 #![warn(clippy::suspicious, clippy::complexity, clippy::perf, clippy::pedantic)]
 #![warn(
@@ -30,7 +30,9 @@
 )]
 #![deny(clippy::unwrap_used)]
 
-use aoc_framework::{OutputHandler, ParseError, RunnableSolution};
+use aoc_framework::{
+    OutputHandler, PartSelection, Registry, RunError, SolutionEntry,
+};
 use thiserror::Error;
 
 // TODO possible packages to add later:
@@ -53,50 +55,143 @@ pub mod day09;
 pub mod day10;
 pub mod day11;
 
-/// Run a solution based on the day.
+/// The registry of solutions by day.
+pub static SOLUTIONS: Registry = Registry::new(&[
+    // --- REGISTER SOLUTIONS HERE ---
+    SolutionEntry::new::<day00::Day00>(0),
+    SolutionEntry::new::<day01::Day01>(1),
+    SolutionEntry::new::<day02::Day02>(2),
+    SolutionEntry::new::<day03::Day03>(3),
+    SolutionEntry::new::<day04::Day04>(4),
+    SolutionEntry::new::<day05::Day05>(5),
+    SolutionEntry::new::<day06::Day06>(6),
+    SolutionEntry::new::<day07::Day07>(7),
+    SolutionEntry::new::<day08::Day08>(8),
+    SolutionEntry::new::<day09::Day09>(9),
+    SolutionEntry::new::<day10::Day10>(10),
+    SolutionEntry::new::<day11::Day11>(11),
+]);
+
+/// Get the puzzle days with registered solutions.
+///
+/// This excludes the example solution of day 0.
+pub fn puzzle_days() -> impl Iterator<Item = u8> {
+    SOLUTIONS.days().filter(|&day| day != 0)
+}
+
+/// Get the registered solution for a day.
+///
+/// # Errors
 ///
-/// See [`RunnableSolution::run`] for arguments used.
+/// If no solution is registered for the given day, a
+/// [`DaySolutionError::DayNotImplemented`] is returned.
+pub fn get_solution(
+    day: u8,
+) -> Result<&'static SolutionEntry, DaySolutionError> {
+    SOLUTIONS
+        .get(day)
+        .ok_or_else(|| DaySolutionError::DayNotImplemented {
+            day,
+            available: SOLUTIONS.days().collect(),
+        })
+}
+
+/// Run selected parts of a solution based on the day.
+///
+/// See [`RunnableSolution::run_parts`][aoc_framework::RunnableSolution::run_parts]
+/// for arguments used.
 ///
 /// # Errors
 ///
-/// If the solution for the given day is not yet implemented, a
+/// If no solution is registered for the given day, a
 /// [`DaySolutionError::DayNotImplemented`] is returned.
 ///
-/// If parsing the input for the solution fails, a
-/// [`DaySolutionError::ParseError`] is returned.
+/// If parsing the input fails, or parsing or a part panics, a
+/// [`DaySolutionError::RunError`] is returned.
 pub fn run_day(
     day: u8,
     handler: &mut dyn OutputHandler,
     input: &str,
+    parts: PartSelection,
     timed: bool,
 ) -> Result<(), DaySolutionError> {
-    match day {
-        // --- MATCH SOLUTIONS HERE ---
-        0 => day00::Day00::run(handler, input, timed),
-        1 => day01::Day01::run(handler, input, timed),
-        2 => day02::Day02::run(handler, input, timed),
-        3 => day03::Day03::run(handler, input, timed),
-        4 => day04::Day04::run(handler, input, timed),
-        5 => day05::Day05::run(handler, input, timed),
-        6 => day06::Day06::run(handler, input, timed),
-        7 => day07::Day07::run(handler, input, timed),
-        8 => day08::Day08::run(handler, input, timed),
-        9 => day09::Day09::run(handler, input, timed),
-        10 => day10::Day10::run(handler, input, timed),
-        11 => day11::Day11::run(handler, input, timed),
-        _ => return Err(DaySolutionError::DayNotImplemented(day)),
-    }
-    .map_err(DaySolutionError::from)
+    get_solution(day)?
+        .run_parts(handler, input, parts, timed)
+        .map_err(DaySolutionError::from)
+}
+
+/// Format a list of days for display.
+fn format_days(days: &[u8]) -> String {
+    days.iter()
+        .map(u8::to_string)
+        .collect::<Vec<_>>()
+        .join(", ")
 }
 
 /// An error that can occur when running a day's solution.
 #[derive(Error, Debug)]
 pub enum DaySolutionError {
     /// The solution for the given day is not yet implemented.
-    #[error("solution for day {0} not yet implemented")]
-    DayNotImplemented(u8),
+    #[error(
+        "solution for day {day} not yet implemented (available days: {})",
+        format_days(.available)
+    )]
+    DayNotImplemented {
+        /// The day requested.
+        day: u8,
+        /// The days with registered solutions.
+        available: Vec<u8>,
+    },
+
+    /// The solution failed to parse input, or parsing or a part panicked.
+    #[error(transparent)]
+    RunError(#[from] RunError),
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use aoc_framework::SolutionPart;
+    use aoc_framework::testing::NullHandler;
 
-    /// The solution failed to parse input.
-    #[error("solution failed to parse input")]
-    ParseError(#[from] ParseError),
+    use super::*;
+
+    #[test]
+    fn registered_days_are_unique() {
+        let mut seen = HashSet::new();
+        for day in SOLUTIONS.days() {
+            assert!(seen.insert(day), "day {day} registered more than once");
+        }
+    }
+
+    #[test]
+    fn run_day_reports_available_days() {
+        let result =
+            run_day(25, &mut NullHandler, "", PartSelection::All, false);
+        match result {
+            Err(DaySolutionError::DayNotImplemented { day, available }) => {
+                assert_eq!(day, 25);
+                assert_eq!(available, SOLUTIONS.days().collect::<Vec<_>>());
+            }
+            other => panic!("unexpected result: {other:?}"),
+        }
+    }
+
+    #[test]
+    fn run_day_reports_part_panic() {
+        // day 3 panics on a bank of batteries too short to pick from
+        let result =
+            run_day(3, &mut NullHandler, "1\n", PartSelection::All, false);
+        match result {
+            Err(DaySolutionError::RunError(RunError::PartPanic {
+                part,
+                message,
+            })) => {
+                assert_eq!(part, SolutionPart::Part1);
+                assert!(message.contains("bank length"), "{message}");
+            }
+            other => panic!("unexpected result: {other:?}"),
+        }
+    }
 }
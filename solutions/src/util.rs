@@ -1,4 +1,8 @@
 //! Utilities for Advent of Code solutions.
 
+pub mod graph;
+pub mod linalg;
 pub mod matrix;
 pub mod parse;
+pub mod sparse_grid;
+pub mod union_find;
@@ -0,0 +1,203 @@
+//! Utility functions for traversing graphs, described by closures giving the
+//! successors of a node.
+//!
+//! Nodes only need to be hashable, so grids of points, adjacency maps, and
+//! states built on the fly can all be searched.
+
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::hash::Hash;
+
+/// Find every node reachable from start nodes, including the start nodes.
+///
+/// # Arguments
+/// - `starts` - The nodes to start from.
+/// - `successors` - A closure giving the nodes a node steps to.
+///
+/// # Examples
+///
+/// ```ignore
+/// use crate::util::graph::flood_fill;
+///
+/// let reached = flood_fill([3], |&n: &u32| (n < 6).then_some(n + 2));
+/// assert_eq!(reached, HashSet::from([3, 5, 7]));
+/// ```
+pub fn flood_fill<N, S, I>(
+    starts: impl IntoIterator<Item = N>,
+    mut successors: S,
+) -> HashSet<N>
+where
+    N: Clone + Eq + Hash,
+    S: FnMut(&N) -> I,
+    I: IntoIterator<Item = N>,
+{
+    let mut reached = HashSet::new();
+    let mut queue = VecDeque::new();
+    for start in starts {
+        if reached.insert(start.clone()) {
+            queue.push_back(start);
+        }
+    }
+
+    while let Some(node) = queue.pop_front() {
+        for next in successors(&node) {
+            if reached.insert(next.clone()) {
+                queue.push_back(next);
+            }
+        }
+    }
+
+    reached
+}
+
+/// An error counting paths through a graph.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PathCountError {
+    /// A cycle is reachable from the start, so paths could be endless.
+    Cycle,
+    /// The number of paths overflowed.
+    Overflow,
+}
+
+/// Count the paths from a start node to goal nodes in a directed acyclic
+/// graph, without walking each path.
+///
+/// The count of paths from each node is remembered, so each node reachable
+/// from the start is expanded once. A path ends at the first goal it reaches,
+/// and nodes without successors end paths that don't count.
+///
+/// # Arguments
+/// - `start` - The node to start from.
+/// - `successors` - A closure giving the nodes a node steps to.
+/// - `is_goal` - A closure checking a node is a goal.
+///
+/// # Errors
+///
+/// If a cycle is reachable from the start without passing a goal,
+/// [`PathCountError::Cycle`] is returned. If the number of paths overflows,
+/// [`PathCountError::Overflow`] is returned.
+///
+/// # Examples
+///
+/// ```ignore
+/// use crate::util::graph::count_paths;
+///
+/// // ways to climb 5 stairs, taking 1 or 2 at a time
+/// let count = count_paths(0, |&n| [n + 1, n + 2], |&n| n >= 5);
+/// assert_eq!(count, Ok(13));
+/// ```
+pub fn count_paths<N, S, I, G>(
+    start: N,
+    mut successors: S,
+    mut is_goal: G,
+) -> Result<u64, PathCountError>
+where
+    N: Clone + Eq + Hash,
+    S: FnMut(&N) -> I,
+    I: IntoIterator<Item = N>,
+    G: FnMut(&N) -> bool,
+{
+    if is_goal(&start) {
+        return Ok(1);
+    }
+
+    // count of paths from each node, or `None` while the node is expanding
+    let mut counts: HashMap<N, Option<u64>> =
+        HashMap::from([(start.clone(), None)]);
+    // nodes expanding, with the successors left to count and the paths
+    // counted so far
+    let pending = successors(&start).into_iter();
+    let mut stack = vec![(start, pending, 0)];
+
+    loop {
+        let (_, pending, count) =
+            stack.last_mut().expect("the start node is expanded last");
+        let Some(next) = pending.next() else {
+            let (node, _, count) = stack.pop().expect("expanding a node");
+            counts.insert(node, Some(count));
+            match stack.last_mut() {
+                Some((_, _, parent_count)) => {
+                    *parent_count = parent_count
+                        .checked_add(count)
+                        .ok_or(PathCountError::Overflow)?;
+                }
+                None => return Ok(count),
+            }
+            continue;
+        };
+
+        let next_count = match counts.get(&next).copied() {
+            Some(Some(next_count)) => next_count,
+            Some(None) => return Err(PathCountError::Cycle),
+            None if is_goal(&next) => {
+                counts.insert(next, Some(1));
+                1
+            }
+            None => {
+                counts.insert(next.clone(), None);
+                let next_pending = successors(&next).into_iter();
+                stack.push((next, next_pending, 0));
+                continue;
+            }
+        };
+        *count = count
+            .checked_add(next_count)
+            .ok_or(PathCountError::Overflow)?;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A directed graph as an adjacency map.
+    fn graph() -> HashMap<char, Vec<char>> {
+        HashMap::from([
+            ('a', vec!['b', 'c', 'f']),
+            ('b', vec!['c', 'd']),
+            ('c', vec!['d', 'f']),
+            ('d', vec!['e']),
+            ('e', vec![]),
+            ('f', vec!['e']),
+        ])
+    }
+
+    #[test]
+    fn flood_fill_finds_reachable_nodes() {
+        let graph = graph();
+        let reached = flood_fill(['c', 'd'], |node| graph[node].clone());
+        assert_eq!(reached, HashSet::from(['c', 'd', 'e', 'f']));
+    }
+
+    #[test]
+    fn count_paths_counts_each_path() {
+        let graph = graph();
+        let count =
+            count_paths('a', |node| graph[node].clone(), |&node| node == 'e');
+        // a-b-c-d-e, a-b-c-f-e, a-b-d-e, a-c-d-e, a-c-f-e, a-f-e
+        assert_eq!(count, Ok(6));
+    }
+
+    #[test]
+    fn count_paths_ends_paths_at_nodes_without_successors() {
+        let count =
+            count_paths(0u8, |&n| (n < 3).then_some(n + 1), |&n| n == 9);
+        assert_eq!(count, Ok(0));
+    }
+
+    #[test]
+    fn count_paths_errors_on_cycle() {
+        let count = count_paths(0u8, |&n| [(n + 1) % 3, 5], |&n| n == 5);
+        assert_eq!(count, Err(PathCountError::Cycle));
+    }
+
+    #[test]
+    fn count_paths_errors_on_overflow() {
+        // every step doubles the paths, so 70 steps overflow
+        let count = count_paths(
+            (0u8, false),
+            |&(n, _)| [(n + 1, false), (n + 1, true)],
+            |&(n, _)| n == 70,
+        );
+        assert_eq!(count, Err(PathCountError::Overflow));
+    }
+}
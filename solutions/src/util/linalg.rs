@@ -0,0 +1,705 @@
+//! Utility types and functions for exact linear algebra, over rationals or
+//! integers modulo a prime.
+//!
+//! Floating point solving (like [`nalgebra`]'s decompositions) can't tell a
+//! whole number from a close one, so puzzles needing integer solutions solve
+//! exactly with a [`Field`] instead.
+
+use std::fmt;
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+use nalgebra::{DMatrix, DVector, Scalar};
+
+/// A number type closed under exact addition, subtraction, multiplication, and
+/// division (by non-zero values), so it can be row reduced.
+///
+/// Types with bounded representations, like [`Rational`], report results they
+/// can't represent through the checked operations rather than panicking.
+pub trait Field:
+    Scalar
+    + Copy
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + Neg<Output = Self>
+{
+    /// The additive identity.
+    const ZERO: Self;
+    /// The multiplicative identity.
+    const ONE: Self;
+
+    /// Add, returning `None` if the sum can't be represented.
+    fn checked_add(self, rhs: Self) -> Option<Self>;
+
+    /// Subtract, returning `None` if the difference can't be represented.
+    fn checked_sub(self, rhs: Self) -> Option<Self>;
+
+    /// Multiply, returning `None` if the product can't be represented.
+    fn checked_mul(self, rhs: Self) -> Option<Self>;
+
+    /// Divide, returning `None` if dividing by zero or the quotient can't be
+    /// represented.
+    fn checked_div(self, rhs: Self) -> Option<Self>;
+}
+
+/// Calculate the greatest common divisor of integers, as non-negative.
+fn gcd(a: i128, b: i128) -> i128 {
+    let (mut a, mut b) = (a.abs(), b.abs());
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
+/// Calculate the least common multiple of integers, as non-negative.
+///
+/// Returns `None` if the multiple overflows an `i64`.
+#[must_use]
+pub fn checked_lcm(a: i64, b: i64) -> Option<i64> {
+    if a == 0 || b == 0 {
+        return Some(0);
+    }
+    let (a, b) = (i128::from(a), i128::from(b));
+    i64::try_from((a / gcd(a, b) * b).abs()).ok()
+}
+
+/// An exact rational number, kept in lowest terms with a positive
+/// denominator.
+///
+/// Arithmetic calculates with `i128` before reducing. The checked operations
+/// return `None` if a reduced result overflows `i64`, and the operators
+/// panic.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Rational {
+    numerator: i64,
+    denominator: i64,
+}
+
+impl Rational {
+    /// Create a rational from a numerator and denominator, reduced to lowest
+    /// terms.
+    ///
+    /// # Panics
+    ///
+    /// If the denominator is zero, or the reduced rational overflows `i64`.
+    #[must_use]
+    pub fn new(numerator: i64, denominator: i64) -> Self {
+        Self::checked_new(numerator, denominator)
+            .expect("rational has a zero denominator or overflowed i64")
+    }
+
+    /// Create a rational from a numerator and denominator, reduced to lowest
+    /// terms.
+    ///
+    /// Returns `None` if the denominator is zero, or the reduced rational
+    /// overflows `i64`.
+    #[must_use]
+    pub fn checked_new(numerator: i64, denominator: i64) -> Option<Self> {
+        Self::checked_reduce(i128::from(numerator), i128::from(denominator))
+    }
+
+    /// Reduce a wide fraction to a rational in lowest terms, or `None` if the
+    /// denominator is zero or the rational overflows `i64`.
+    fn checked_reduce(numerator: i128, denominator: i128) -> Option<Self> {
+        if denominator == 0 {
+            return None;
+        }
+        let divisor = gcd(numerator, denominator) * denominator.signum();
+        Some(Self {
+            numerator: i64::try_from(numerator / divisor).ok()?,
+            denominator: i64::try_from(denominator / divisor).ok()?,
+        })
+    }
+
+    /// Get the numerator, holding the sign of the rational.
+    #[must_use]
+    pub fn numerator(self) -> i64 {
+        self.numerator
+    }
+
+    /// Get the denominator, which is always positive.
+    #[must_use]
+    pub fn denominator(self) -> i64 {
+        self.denominator
+    }
+}
+
+impl From<i64> for Rational {
+    fn from(value: i64) -> Self {
+        Self::new(value, 1)
+    }
+}
+
+impl fmt::Display for Rational {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.denominator == 1 {
+            write!(f, "{}", self.numerator)
+        } else {
+            write!(f, "{}/{}", self.numerator, self.denominator)
+        }
+    }
+}
+
+impl Add for Rational {
+    type Output = Self;
+
+    /// # Panics
+    ///
+    /// If the sum overflows `i64`.
+    fn add(self, rhs: Self) -> Self::Output {
+        self.checked_add(rhs).expect("rational sum overflowed i64")
+    }
+}
+
+impl Sub for Rational {
+    type Output = Self;
+
+    /// # Panics
+    ///
+    /// If the difference overflows `i64`.
+    fn sub(self, rhs: Self) -> Self::Output {
+        self.checked_sub(rhs)
+            .expect("rational difference overflowed i64")
+    }
+}
+
+impl Mul for Rational {
+    type Output = Self;
+
+    /// # Panics
+    ///
+    /// If the product overflows `i64`.
+    fn mul(self, rhs: Self) -> Self::Output {
+        self.checked_mul(rhs)
+            .expect("rational product overflowed i64")
+    }
+}
+
+impl Div for Rational {
+    type Output = Self;
+
+    /// # Panics
+    ///
+    /// If dividing by zero, or the quotient overflows `i64`.
+    fn div(self, rhs: Self) -> Self::Output {
+        self.checked_div(rhs)
+            .expect("rational division by zero or overflowed i64")
+    }
+}
+
+impl Neg for Rational {
+    type Output = Self;
+
+    /// # Panics
+    ///
+    /// If the numerator is `i64::MIN`.
+    fn neg(self) -> Self::Output {
+        Self::ZERO
+            .checked_sub(self)
+            .expect("rational negation overflowed i64")
+    }
+}
+
+impl Field for Rational {
+    const ZERO: Self = Self {
+        numerator: 0,
+        denominator: 1,
+    };
+    const ONE: Self = Self {
+        numerator: 1,
+        denominator: 1,
+    };
+
+    fn checked_add(self, rhs: Self) -> Option<Self> {
+        Self::checked_reduce(
+            i128::from(self.numerator) * i128::from(rhs.denominator)
+                + i128::from(rhs.numerator) * i128::from(self.denominator),
+            i128::from(self.denominator) * i128::from(rhs.denominator),
+        )
+    }
+
+    fn checked_sub(self, rhs: Self) -> Option<Self> {
+        Self::checked_reduce(
+            i128::from(self.numerator) * i128::from(rhs.denominator)
+                - i128::from(rhs.numerator) * i128::from(self.denominator),
+            i128::from(self.denominator) * i128::from(rhs.denominator),
+        )
+    }
+
+    fn checked_mul(self, rhs: Self) -> Option<Self> {
+        Self::checked_reduce(
+            i128::from(self.numerator) * i128::from(rhs.numerator),
+            i128::from(self.denominator) * i128::from(rhs.denominator),
+        )
+    }
+
+    fn checked_div(self, rhs: Self) -> Option<Self> {
+        Self::checked_reduce(
+            i128::from(self.numerator) * i128::from(rhs.denominator),
+            i128::from(self.denominator) * i128::from(rhs.numerator),
+        )
+    }
+}
+
+/// An integer modulo a prime `P`, kept in `0..P`.
+///
+/// Division multiplies by the inverse from Fermat's little theorem, so `P`
+/// must be prime.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ModInt<const P: u64>(u64);
+
+/// An integer modulo 2, where addition is exclusive or, for problems of
+/// toggling.
+pub type Gf2 = ModInt<2>;
+
+impl<const P: u64> ModInt<P> {
+    /// Create an integer modulo `P` from any integer.
+    #[must_use]
+    pub fn new(value: u64) -> Self {
+        Self(value % P)
+    }
+
+    /// Get the value, in `0..P`.
+    #[must_use]
+    pub fn value(self) -> u64 {
+        self.0
+    }
+
+    /// Reduce a wide integer, like a sum or product of values without
+    /// overflow.
+    fn reduce(value: u128) -> Self {
+        Self(u64::try_from(value % u128::from(P)).unwrap_or_else(|_| {
+            unreachable!("a value modulo a u64 fits a u64")
+        }))
+    }
+
+    /// Raise to a power by repeated squaring.
+    fn pow(self, mut exponent: u64) -> Self {
+        let (mut base, mut result) = (self, Self::ONE);
+        while exponent > 0 {
+            if exponent & 1 == 1 {
+                result = result * base;
+            }
+            base = base * base;
+            exponent >>= 1;
+        }
+        result
+    }
+}
+
+impl<const P: u64> From<bool> for ModInt<P> {
+    fn from(value: bool) -> Self {
+        Self::new(u64::from(value))
+    }
+}
+
+impl<const P: u64> fmt::Display for ModInt<P> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl<const P: u64> Add for ModInt<P> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self::reduce(u128::from(self.0) + u128::from(rhs.0))
+    }
+}
+
+impl<const P: u64> Sub for ModInt<P> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        self + -rhs
+    }
+}
+
+impl<const P: u64> Mul for ModInt<P> {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        Self::reduce(u128::from(self.0) * u128::from(rhs.0))
+    }
+}
+
+impl<const P: u64> Div for ModInt<P> {
+    type Output = Self;
+
+    /// # Panics
+    ///
+    /// If dividing by zero.
+    fn div(self, rhs: Self) -> Self::Output {
+        self.checked_div(rhs)
+            .unwrap_or_else(|| panic!("division by zero modulo {P}"))
+    }
+}
+
+impl<const P: u64> Neg for ModInt<P> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self((P - self.0) % P)
+    }
+}
+
+/// Arithmetic modulo `P` never overflows, so only dividing by zero fails.
+impl<const P: u64> Field for ModInt<P> {
+    const ZERO: Self = Self(0);
+    const ONE: Self = Self(1 % P);
+
+    fn checked_add(self, rhs: Self) -> Option<Self> {
+        Some(self + rhs)
+    }
+
+    fn checked_sub(self, rhs: Self) -> Option<Self> {
+        Some(self - rhs)
+    }
+
+    fn checked_mul(self, rhs: Self) -> Option<Self> {
+        Some(self * rhs)
+    }
+
+    fn checked_div(self, rhs: Self) -> Option<Self> {
+        (rhs.0 != 0).then(|| self * rhs.pow(P - 2))
+    }
+}
+
+/// A matrix in reduced row echelon form, with the columns of its pivots.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RowEchelon<F: Field> {
+    /// The reduced matrix.
+    matrix: DMatrix<F>,
+    /// The column of each row's pivot, for rows that aren't all zero.
+    pivots: Vec<usize>,
+}
+
+impl<F: Field> RowEchelon<F> {
+    /// Reduce a matrix to reduced row echelon form with Gauss-Jordan
+    /// elimination.
+    ///
+    /// Returns `None` if a value overflows while reducing.
+    #[must_use]
+    pub fn new(mut matrix: DMatrix<F>) -> Option<Self> {
+        let (nrows, ncols) = matrix.shape();
+        let mut pivots = Vec::new();
+
+        for col in 0..ncols {
+            let rank = pivots.len();
+            let Some(pivot_row) =
+                (rank..nrows).find(|&row| matrix[(row, col)] != F::ZERO)
+            else {
+                continue;
+            };
+            matrix.swap_rows(rank, pivot_row);
+
+            // scale the pivot to one, then clear the column of other rows
+            let inverse = F::ONE.checked_div(matrix[(rank, col)])?;
+            for value in matrix.row_mut(rank).iter_mut() {
+                *value = value.checked_mul(inverse)?;
+            }
+            for row in 0..nrows {
+                let factor = matrix[(row, col)];
+                if row == rank || factor == F::ZERO {
+                    continue;
+                }
+                for c in col..ncols {
+                    let cleared = factor.checked_mul(matrix[(rank, c)])?;
+                    matrix[(row, c)] = matrix[(row, c)].checked_sub(cleared)?;
+                }
+            }
+
+            pivots.push(col);
+            if pivots.len() == nrows {
+                break;
+            }
+        }
+
+        Some(Self { matrix, pivots })
+    }
+
+    /// Get the reduced matrix.
+    #[must_use]
+    pub fn matrix(&self) -> &DMatrix<F> {
+        &self.matrix
+    }
+
+    /// Get the column of each row's pivot, for rows that aren't all zero.
+    #[must_use]
+    pub fn pivots(&self) -> &[usize] {
+        &self.pivots
+    }
+
+    /// Get the rank, the number of rows that aren't all zero.
+    #[must_use]
+    pub fn rank(&self) -> usize {
+        self.pivots.len()
+    }
+
+    /// Get the columns without a pivot, which are free variables of a system.
+    #[must_use]
+    pub fn free_columns(&self) -> Vec<usize> {
+        (0..self.matrix.ncols())
+            .filter(|col| !self.pivots.contains(col))
+            .collect()
+    }
+
+    /// Get a basis of the null space: a vector per free column, setting that
+    /// free variable to one and the other free variables to zero.
+    ///
+    /// Returns `None` if negating a value overflows.
+    #[must_use]
+    pub fn null_space(&self) -> Option<Vec<DVector<F>>> {
+        self.free_columns()
+            .into_iter()
+            .map(|free| {
+                let mut vector =
+                    DVector::from_element(self.matrix.ncols(), F::ZERO);
+                vector[free] = F::ONE;
+                for (row, &pivot) in self.pivots.iter().enumerate() {
+                    vector[pivot] =
+                        F::ZERO.checked_sub(self.matrix[(row, free)])?;
+                }
+                Some(vector)
+            })
+            .collect()
+    }
+}
+
+/// Every solution of a linear system `Ax = b`: a particular solution plus any
+/// combination of the null space basis.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Solution<F: Field> {
+    /// A solution with every free variable as zero.
+    pub particular: DVector<F>,
+    /// A basis of the null space of `A`, a vector per free variable.
+    pub null_space: Vec<DVector<F>>,
+    /// The variable each null space vector sets to one, by vector.
+    pub free_variables: Vec<usize>,
+}
+
+/// An error solving a linear system.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SystemError {
+    /// No values of the variables satisfy every equation.
+    Inconsistent,
+    /// A value overflowed while solving.
+    Overflow,
+}
+
+/// Solve a linear system `Ax = b` exactly.
+///
+/// Integer systems solve as [`Rational`] matrices, where checking for whole
+/// solutions is up to the caller.
+///
+/// # Returns
+///
+/// Every solution.
+///
+/// # Errors
+///
+/// If the system is inconsistent, [`SystemError::Inconsistent`] is returned.
+/// If a value overflows while solving, [`SystemError::Overflow`] is returned.
+///
+/// # Panics
+///
+/// If `b` doesn't have a value per row of `A`.
+///
+/// # Examples
+///
+/// ```ignore
+/// use nalgebra::{DMatrix, DVector};
+///
+/// use crate::util::linalg::{Rational, solve};
+///
+/// // x + y = 3 and x - y = 1
+/// let a = DMatrix::from_row_slice(2, 2, &[1, 1, 1, -1]).map(Rational::from);
+/// let b = DVector::from_row_slice(&[3, 1]).map(Rational::from);
+/// let solution = solve(&a, &b)?;
+/// assert_eq!(solution.particular, DVector::from_row_slice(&[2, 1]).map(Rational::from));
+/// assert!(solution.null_space.is_empty());
+/// ```
+pub fn solve<F: Field>(
+    a: &DMatrix<F>,
+    b: &DVector<F>,
+) -> Result<Solution<F>, SystemError> {
+    let (nrows, ncols) = a.shape();
+    assert_eq!(b.len(), nrows, "expected a value of b per row of A");
+
+    let coefficients =
+        RowEchelon::new(a.clone()).ok_or(SystemError::Overflow)?;
+    let augmented =
+        RowEchelon::new(DMatrix::from_fn(nrows, ncols + 1, |row, col| {
+            if col < ncols { a[(row, col)] } else { b[row] }
+        }))
+        .ok_or(SystemError::Overflow)?;
+    // a pivot in the augmented column means a row of 0 = non-zero
+    if augmented.rank() > coefficients.rank() {
+        return Err(SystemError::Inconsistent);
+    }
+
+    let mut particular = DVector::from_element(ncols, F::ZERO);
+    for (row, &pivot) in augmented.pivots().iter().enumerate() {
+        particular[pivot] = augmented.matrix()[(row, ncols)];
+    }
+
+    Ok(Solution {
+        particular,
+        null_space: coefficients.null_space().ok_or(SystemError::Overflow)?,
+        free_variables: coefficients.free_columns(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rational_matrix(
+        nrows: usize,
+        ncols: usize,
+        values: &[i64],
+    ) -> DMatrix<Rational> {
+        DMatrix::from_row_slice(nrows, ncols, values).map(Rational::from)
+    }
+
+    /// Multiply a matrix by a vector.
+    fn product<F: Field>(a: &DMatrix<F>, x: &DVector<F>) -> DVector<F> {
+        DVector::from_iterator(
+            a.nrows(),
+            a.row_iter().map(|row| {
+                row.iter()
+                    .zip(x.iter())
+                    .fold(F::ZERO, |acc, (&value, &variable)| {
+                        acc + value * variable
+                    })
+            }),
+        )
+    }
+
+    #[test]
+    fn rational_arithmetic_is_exact() {
+        let third = Rational::new(1, 3);
+        let sixth = Rational::new(-2, -12);
+        assert_eq!(third + sixth, Rational::new(1, 2));
+        assert_eq!(third - sixth * Rational::from(2), Rational::ZERO);
+        assert_eq!(third / Rational::new(-2, 3), Rational::new(-1, 2));
+        assert_eq!(Rational::new(4, -6).denominator(), 3);
+        assert_eq!(Rational::new(4, -6).numerator(), -2);
+        assert_eq!(Rational::new(4, -6).to_string(), "-2/3");
+    }
+
+    #[test]
+    fn rational_checked_arithmetic_detects_overflow() {
+        let max = Rational::from(i64::MAX);
+        assert_eq!(Rational::checked_new(i64::MIN, -1), None);
+        assert_eq!(Rational::checked_new(1, 0), None);
+        assert_eq!(max.checked_add(Rational::ONE), None);
+        assert_eq!(Rational::ZERO.checked_sub(Rational::from(i64::MIN)), None);
+        assert_eq!(max.checked_mul(Rational::from(2)), None);
+        assert_eq!(max.checked_div(Rational::new(1, 2)), None);
+        assert_eq!(max.checked_div(Rational::ZERO), None);
+        assert_eq!(
+            max.checked_add(Rational::from(-1)),
+            Some(Rational::from(i64::MAX - 1))
+        );
+    }
+
+    #[test]
+    fn checked_lcm_detects_overflow() {
+        assert_eq!(checked_lcm(4, -6), Some(12));
+        assert_eq!(checked_lcm(0, 5), Some(0));
+        assert_eq!(checked_lcm(i64::MAX, i64::MAX - 1), None);
+    }
+
+    #[test]
+    fn mod_int_arithmetic_wraps() {
+        type Mod7 = ModInt<7>;
+        assert_eq!(Mod7::new(5) + Mod7::new(4), Mod7::new(2));
+        assert_eq!(Mod7::new(2) - Mod7::new(5), Mod7::new(4));
+        assert_eq!(Mod7::new(3) * Mod7::new(5), Mod7::ONE);
+        assert_eq!(Mod7::ONE / Mod7::new(3), Mod7::new(5));
+        assert_eq!(Gf2::ONE + Gf2::ONE, Gf2::ZERO);
+        assert_eq!(Gf2::from(true).value(), 1);
+        assert_eq!(Mod7::new(3).checked_div(Mod7::ZERO), None);
+    }
+
+    #[test]
+    fn row_echelon_reduces_and_ranks() {
+        let echelon = RowEchelon::new(rational_matrix(
+            3,
+            3,
+            &[2, 4, 6, 1, 2, 4, 3, 6, 10],
+        ))
+        .expect("expected reduction without overflow");
+        assert_eq!(echelon.rank(), 2);
+        assert_eq!(echelon.pivots(), [0, 2]);
+        assert_eq!(echelon.free_columns(), [1]);
+        assert_eq!(
+            *echelon.matrix(),
+            rational_matrix(3, 3, &[1, 2, 0, 0, 0, 1, 0, 0, 0])
+        );
+    }
+
+    #[test]
+    fn null_space_vectors_are_solutions_of_zero() {
+        let a = rational_matrix(2, 4, &[1, 2, 0, -1, 0, 0, 1, 3]);
+        let null_space = RowEchelon::new(a.clone())
+            .and_then(|echelon| echelon.null_space())
+            .expect("expected reduction without overflow");
+        assert_eq!(null_space.len(), 2);
+        for vector in null_space {
+            assert!(
+                product(&a, &vector).iter().all(|&v| v == Rational::ZERO),
+                "expected null space vector: {vector:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn solve_finds_rational_solutions() {
+        let a = rational_matrix(2, 3, &[1, 1, 1, 0, 2, 1]);
+        let b = DVector::from_row_slice(&[3, 1]).map(Rational::from);
+        let solution = solve(&a, &b).expect("expected a solution");
+        assert_eq!(
+            solution.particular,
+            DVector::from_row_slice(&[
+                Rational::new(5, 2),
+                Rational::new(1, 2),
+                Rational::ZERO
+            ])
+        );
+        assert_eq!(solution.free_variables, [2]);
+        assert_eq!(product(&a, &solution.particular), b);
+        assert_eq!(
+            product(&a, &solution.null_space[0]),
+            DVector::from_element(2, Rational::ZERO)
+        );
+    }
+
+    #[test]
+    fn solve_detects_inconsistent_systems() {
+        let a = rational_matrix(2, 2, &[1, 1, 2, 2]);
+        let b = DVector::from_row_slice(&[1, 3]).map(Rational::from);
+        assert_eq!(solve(&a, &b), Err(SystemError::Inconsistent));
+    }
+
+    #[test]
+    fn solve_detects_overflow() {
+        // clearing x from the second row adds 1 to i64::MAX
+        let a = rational_matrix(2, 2, &[1, 1, -1, i64::MAX]);
+        let b = DVector::from_row_slice(&[0, 0]).map(Rational::from);
+        assert_eq!(solve(&a, &b), Err(SystemError::Overflow));
+    }
+
+    #[test]
+    fn solve_toggles_over_gf2() {
+        // toggling lights 0 & 1, 1 & 2, or 0 & 2 to turn on lights 0 & 2
+        let a = DMatrix::from_row_slice(3, 3, &[1, 0, 1, 1, 1, 0, 0, 1, 1])
+            .map(Gf2::new);
+        let b = DVector::from_row_slice(&[1, 0, 1]).map(Gf2::new);
+        let solution = solve(&a, &b).expect("expected a solution");
+        assert_eq!(solution.null_space.len(), 1);
+        assert_eq!(product(&a, &solution.particular), b);
+    }
+}
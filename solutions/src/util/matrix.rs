@@ -1,6 +1,7 @@
-//! Utility trait for managing matrixes with points.
+//! Utility trait for managing matrixes with points, and directions to move
+//! between points.
 
-use nalgebra::{DMatrix, Point2};
+use nalgebra::{DMatrix, Point2, Vector2};
 
 /// A point type expected for use with [`MatrixPointAccess`].
 ///
@@ -18,6 +19,134 @@ pub fn matrix_point_from_usize(x: usize, y: usize) -> MatrixPoint {
     MatrixPoint::new(cast_x, cast_y)
 }
 
+/// A direction to move between points.
+///
+/// Points index matrixes by column then row, so north is towards the first row
+/// (decreasing y) and east is towards the last column (increasing x).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction {
+    /// Towards the first row.
+    North,
+    /// Towards the first row and last column.
+    NorthEast,
+    /// Towards the last column.
+    East,
+    /// Towards the last row and last column.
+    SouthEast,
+    /// Towards the last row.
+    South,
+    /// Towards the last row and first column.
+    SouthWest,
+    /// Towards the first column.
+    West,
+    /// Towards the first row and first column.
+    NorthWest,
+}
+
+impl Direction {
+    /// All directions, clockwise from north.
+    pub const ALL: [Self; 8] = [
+        Self::North,
+        Self::NorthEast,
+        Self::East,
+        Self::SouthEast,
+        Self::South,
+        Self::SouthWest,
+        Self::West,
+        Self::NorthWest,
+    ];
+    /// The cardinal directions, clockwise from north.
+    pub const CARDINAL: [Self; 4] =
+        [Self::North, Self::East, Self::South, Self::West];
+    /// The diagonal directions, clockwise from north-east.
+    pub const DIAGONAL: [Self; 4] = [
+        Self::NorthEast,
+        Self::SouthEast,
+        Self::SouthWest,
+        Self::NorthWest,
+    ];
+
+    /// Get the offset to move one step in the direction.
+    #[must_use]
+    pub fn offset(self) -> Vector2<i32> {
+        match self {
+            Self::North => Vector2::new(0, -1),
+            Self::NorthEast => Vector2::new(1, -1),
+            Self::East => Vector2::new(1, 0),
+            Self::SouthEast => Vector2::new(1, 1),
+            Self::South => Vector2::new(0, 1),
+            Self::SouthWest => Vector2::new(-1, 1),
+            Self::West => Vector2::new(-1, 0),
+            Self::NorthWest => Vector2::new(-1, -1),
+        }
+    }
+
+    /// Get the point one step from a point in the direction, if it doesn't
+    /// overflow.
+    #[must_use]
+    pub fn step(self, point: MatrixPoint) -> Option<MatrixPoint> {
+        let offset = self.offset();
+        Some(MatrixPoint::new(
+            point.x.checked_add(offset.x)?,
+            point.y.checked_add(offset.y)?,
+        ))
+    }
+
+    /// Rotate the direction clockwise by eighths of a turn, so 2 turns right
+    /// and 6 turns left.
+    #[must_use]
+    pub fn rotate_clockwise(self, eighths: usize) -> Self {
+        // the index in all directions
+        let index = match self {
+            Self::North => 0,
+            Self::NorthEast => 1,
+            Self::East => 2,
+            Self::SouthEast => 3,
+            Self::South => 4,
+            Self::SouthWest => 5,
+            Self::West => 6,
+            Self::NorthWest => 7,
+        };
+        let count = Self::ALL.len();
+        Self::ALL[(index + eighths % count) % count]
+    }
+
+    /// Turn the direction right, a quarter turn clockwise.
+    #[must_use]
+    pub fn turn_right(self) -> Self {
+        self.rotate_clockwise(2)
+    }
+
+    /// Turn the direction left, a quarter turn counterclockwise.
+    #[must_use]
+    pub fn turn_left(self) -> Self {
+        self.rotate_clockwise(6)
+    }
+}
+
+/// A set of directions to find the neighbors of a point with.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Neighborhood {
+    /// The 4 neighbors in cardinal directions.
+    VonNeumann,
+    /// The 8 neighbors in cardinal and diagonal directions.
+    Moore,
+}
+
+impl Neighborhood {
+    /// Get an iterator of the directions to neighbors, cardinal directions
+    /// first.
+    pub fn directions(self) -> impl Iterator<Item = Direction> {
+        let diagonal: &[Direction] = match self {
+            Self::VonNeumann => &[],
+            Self::Moore => &Direction::DIAGONAL,
+        };
+        Direction::CARDINAL
+            .into_iter()
+            .chain(diagonal.iter().copied())
+    }
+}
+
 /// An extension trait to manage matrix indexes via points.
 pub trait MatrixPointAccess<T> {
     /// Check the matrix can be indexed by a point.
@@ -28,6 +157,34 @@ pub trait MatrixPointAccess<T> {
     fn get_at_point_mut(&mut self, point: MatrixPoint) -> Option<&mut T>;
     /// Get an iterator of points that can index the matrix.
     fn points(&self) -> impl Iterator<Item = MatrixPoint> + '_;
+
+    /// Get an iterator of the neighbors of a point that can index the matrix.
+    fn neighbors(
+        &self,
+        point: MatrixPoint,
+        neighborhood: Neighborhood,
+    ) -> impl Iterator<Item = MatrixPoint> + '_ {
+        neighborhood
+            .directions()
+            .filter_map(move |direction| direction.step(point))
+            .filter(|&neighbor| self.contains_point(neighbor))
+    }
+
+    /// Get an iterator of the points stepping from a point (excluded) in a
+    /// direction, until a point can't index the matrix.
+    ///
+    /// A matrix every point can index makes the iterator only end on
+    /// overflow.
+    fn cast_ray(
+        &self,
+        start: MatrixPoint,
+        direction: Direction,
+    ) -> impl Iterator<Item = MatrixPoint> + '_ {
+        std::iter::successors(direction.step(start), move |&point| {
+            direction.step(point)
+        })
+        .take_while(|&point| self.contains_point(point))
+    }
 }
 
 impl<T> MatrixPointAccess<T> for DMatrix<T> {
@@ -220,4 +377,79 @@ mod tests {
         ]);
         assert_eq!(generated, expected);
     }
+
+    #[test]
+    fn direction_rotates_clockwise() {
+        assert_eq!(Direction::North.turn_right(), Direction::East);
+        assert_eq!(Direction::West.turn_right(), Direction::North);
+        assert_eq!(Direction::NorthWest.turn_right(), Direction::NorthEast);
+        assert_eq!(Direction::East.rotate_clockwise(6), Direction::North);
+        assert_eq!(Direction::South.rotate_clockwise(9), Direction::SouthWest);
+        assert_eq!(
+            Direction::NorthWest.rotate_clockwise(usize::MAX),
+            Direction::West
+        );
+        assert_eq!(Direction::North.turn_left(), Direction::West);
+        assert_eq!(Direction::SouthEast.turn_left(), Direction::NorthEast);
+    }
+
+    #[test]
+    fn direction_step_stops_on_overflow() {
+        let point = MatrixPoint::new(i32::MAX, 0);
+        assert_eq!(Direction::East.step(point), None);
+        assert_eq!(
+            Direction::SouthWest.step(point),
+            Some(MatrixPoint::new(i32::MAX - 1, 1))
+        );
+    }
+
+    #[test]
+    fn neighbors_are_filtered_by_bounds() {
+        let matrix = DMatrix::from_iterator(3, 3, 0..9);
+
+        let center: HashSet<MatrixPoint> = matrix
+            .neighbors(MatrixPoint::new(1, 1), Neighborhood::Moore)
+            .collect();
+        assert_eq!(center.len(), 8);
+        assert!(!center.contains(&MatrixPoint::new(1, 1)));
+
+        let corner: HashSet<MatrixPoint> = matrix
+            .neighbors(MatrixPoint::origin(), Neighborhood::VonNeumann)
+            .collect();
+        assert_eq!(
+            corner,
+            HashSet::from([Point2::new(1, 0), Point2::new(0, 1)])
+        );
+        assert_eq!(
+            matrix
+                .neighbors(MatrixPoint::origin(), Neighborhood::Moore)
+                .count(),
+            3
+        );
+    }
+
+    #[test]
+    fn cast_ray_stops_at_bounds() {
+        let matrix = DMatrix::from_iterator(3, 4, 0..12);
+
+        let ray: Vec<MatrixPoint> = matrix
+            .cast_ray(MatrixPoint::new(0, 2), Direction::East)
+            .collect();
+        assert_eq!(
+            ray,
+            [Point2::new(1, 2), Point2::new(2, 2), Point2::new(3, 2)]
+        );
+
+        let ray: Vec<MatrixPoint> = matrix
+            .cast_ray(MatrixPoint::new(1, 1), Direction::NorthWest)
+            .collect();
+        assert_eq!(ray, [Point2::new(0, 0)]);
+
+        assert_eq!(
+            matrix
+                .cast_ray(MatrixPoint::new(3, 0), Direction::NorthEast)
+                .count(),
+            0
+        );
+    }
 }
@@ -1,16 +1,56 @@
 //! Utility functions for parsing input.
 
+use std::num::ParseIntError;
+use std::str::FromStr;
+
 use aoc_framework::{ParseError, ParseResult};
 use nalgebra::{DMatrix, Scalar};
 
 use crate::util::matrix::{MatrixPoint, matrix_point_from_usize};
 
+pub mod pattern;
+
+use pattern::FromField;
+
+/// Wrap an error in a [`ParseError::InvalidColumn`] spanning text that starts
+/// at a byte position of a line.
+fn column_error(
+    line: &str,
+    position: usize,
+    text: &str,
+    source: ParseError,
+) -> ParseError {
+    ParseError::invalid_column_from_zero_index(
+        line[..position].chars().count(),
+        text.chars().count().max(1),
+        source,
+    )
+}
+
+/// Wrap an error in a [`ParseError::InvalidField`] of a zero-based field
+/// index, then a [`ParseError::InvalidColumn`] spanning the field's text.
+fn field_error(
+    line: &str,
+    position: usize,
+    text: &str,
+    index: usize,
+    source: ParseError,
+) -> ParseError {
+    column_error(
+        line,
+        position,
+        text,
+        ParseError::invalid_field_from_zero_index(index, source),
+    )
+}
+
 /// Parse lines with a closure, wrapping any [`ParseError`] in a
 /// [`ParseError::InvalidLine`] error. Allows specifying an offset for line
 /// numbering.
 ///
 /// This function is useful when parsing inputs that are chunks of a larger
 /// input, where line numbers need to reflect their position in the full input.
+/// For chunks separated by blank lines, [`parse_chunks`] computes offsets.
 ///
 /// # Arguments
 /// - `input` - The input string to parse.
@@ -110,10 +150,12 @@ where
 ///
 /// If a line length does not match the first line length, a
 /// [`ParseError::LineLength`] error is created and wrapped in a
-/// [`ParseError::InvalidLine`] for return.
+/// [`ParseError::InvalidColumn`] spanning the extra or first missing
+/// character, then a [`ParseError::InvalidLine`] for return.
 ///
 /// If parsing any character fails, a [`ParseError::InvalidLine`] error is
-/// returned, wrapping the original error.
+/// returned, wrapping a [`ParseError::InvalidColumn`] of the character that
+/// wraps the original error.
 ///
 /// For all [`ParseError::InvalidLine`], the line number will have `offset`
 /// applied.
@@ -145,12 +187,18 @@ where
             ));
         }
         if line.len() != cols {
+            // span the extra characters, or the first missing character
+            let width = line.len().abs_diff(cols).max(1);
             return Err(ParseError::invalid_line_from_zero_index(
                 y.saturating_add(offset),
-                ParseError::LineLength {
-                    expected: cols,
-                    actual: line.len(),
-                },
+                ParseError::invalid_column_from_zero_index(
+                    line.len().min(cols),
+                    width,
+                    ParseError::LineLength {
+                        expected: cols,
+                        actual: line.len(),
+                    },
+                ),
             ));
         }
 
@@ -161,7 +209,9 @@ where
                 Err(source) => {
                     return Err(ParseError::invalid_line_from_zero_index(
                         y.saturating_add(offset),
-                        source,
+                        ParseError::invalid_column_from_zero_index(
+                            x, 1, source,
+                        ),
                     ));
                 }
             }
@@ -192,10 +242,12 @@ where
 ///
 /// If a line length does not match the first line length, a
 /// [`ParseError::LineLength`] error is created and wrapped in a
-/// [`ParseError::InvalidLine`] for return.
+/// [`ParseError::InvalidColumn`] spanning the extra or first missing
+/// character, then a [`ParseError::InvalidLine`] for return.
 ///
 /// If parsing any character fails, a [`ParseError::InvalidLine`] error is
-/// returned, wrapping the original error.
+/// returned, wrapping a [`ParseError::InvalidColumn`] of the character that
+/// wraps the original error.
 pub fn parse_grid<T, F>(input: &str, parser: F) -> ParseResult<DMatrix<T>>
 where
     T: Scalar,
@@ -204,6 +256,247 @@ where
     parse_grid_with_offset(input, 0, parser)
 }
 
+/// The delimiter between chunks of input: a blank line.
+const CHUNK_DELIMITER: &str = "\n\n";
+
+/// A chunk of input separated from others by a blank line, with its position
+/// in the full input.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Chunk<'a> {
+    /// The text of the chunk.
+    pub text: &'a str,
+    /// The zero-based index of the chunk's first line in the full input, to
+    /// offset line numbers by.
+    pub offset: usize,
+    /// The one-based number of the chunk.
+    pub number: usize,
+}
+
+impl<'a> Chunk<'a> {
+    /// Parse the chunk's lines with a closure, numbering lines by their
+    /// position in the full input.
+    ///
+    /// See [`parse_lines_with_offset`] for errors returned.
+    pub fn parse_lines<T, F>(
+        &self,
+        parser: F,
+    ) -> impl Iterator<Item = ParseResult<T>> + use<'a, T, F>
+    where
+        F: FnMut(&str) -> ParseResult<T>,
+    {
+        parse_lines_with_offset(self.text, self.offset, parser)
+    }
+}
+
+/// Split input into chunks separated by blank lines, one per description
+/// given.
+///
+/// Each chunk knows the offset of its first line in the input and its
+/// one-based number, so errors parsing it report positions in the full
+/// input. The last chunk holds the rest of the input, including any further
+/// blank lines.
+///
+/// # Arguments
+/// - `input` - The input string to split.
+/// - `descriptions` - Contextual descriptions of the expected chunks, in
+///   order, for display in errors.
+///
+/// # Errors
+///
+/// If the input has fewer chunks than described, a
+/// [`ParseError::NoChunkDelimiter`] error is returned.
+///
+/// If any chunk is empty, a [`ParseError::EmptyChunk`] error is returned
+/// with the chunk's number and description.
+///
+/// # Examples
+///
+/// ```ignore
+/// use aoc_framework::{ParseError, ParseResult};
+/// use crate::util::parse::parse_chunks;
+///
+/// let input = "Ignore this header line\n\n42\n100\n";
+/// let [_header, data] = parse_chunks(input, ["header", "data"])?;
+/// assert_eq!(data.offset, 2);
+///
+/// let parsed: Vec<u32> = data
+///     .parse_lines(|line| {
+///         line.parse::<u32>()
+///             .map_err(|source| ParseError::parse_int_from_str(line, source))
+///     })
+///     .collect::<ParseResult<_>>()?;
+/// assert_eq!(parsed, vec![42, 100]);
+/// ```
+pub fn parse_chunks<'a, const N: usize>(
+    input: &'a str,
+    descriptions: [&str; N],
+) -> ParseResult<[Chunk<'a>; N]> {
+    let mut rest = input;
+    let mut offset = 0;
+    let mut chunks = Vec::with_capacity(N);
+    for (index, description) in descriptions.into_iter().enumerate() {
+        let text = if index + 1 == N {
+            rest
+        } else {
+            let (text, after) =
+                rest.split_once(CHUNK_DELIMITER).ok_or_else(|| {
+                    ParseError::NoChunkDelimiter(CHUNK_DELIMITER.into())
+                })?;
+            rest = after;
+            text
+        };
+
+        let number = index + 1;
+        if text.is_empty() {
+            return Err(ParseError::EmptyChunk {
+                chunk_number: number,
+                description: description.into(),
+            });
+        }
+        chunks.push(Chunk {
+            text,
+            offset,
+            number,
+        });
+        // skip the chunk's lines and the blank line after
+        offset += text.lines().count() + 1;
+    }
+
+    // the vector was filled with a chunk per description
+    Ok(chunks
+        .try_into()
+        .unwrap_or_else(|_| unreachable!("expected {N} chunks")))
+}
+
+/// Parse a line of exactly `N` fields separated by a delimiter.
+///
+/// The last field holds the rest of the line, so extra delimiters fail to
+/// parse with it.
+///
+/// # Arguments
+/// - `line` - The line to parse.
+/// - `delimiter` - The character separating fields.
+///
+/// # Errors
+///
+/// If a delimiter is missing, a [`ParseError::NoDelimiter`] error is
+/// returned for the first missing field. If a field fails to parse, its
+/// error is returned. Either is wrapped in a [`ParseError::InvalidField`] of
+/// the field, then a [`ParseError::InvalidColumn`] spanning its text (or the
+/// end of the line, if missing).
+///
+/// # Examples
+///
+/// ```ignore
+/// use crate::util::parse::parse_delimited;
+///
+/// let [x, y, z] = parse_delimited::<i32, 3>("1,-2,3", ',')?;
+/// assert_eq!((x, y, z), (1, -2, 3));
+/// assert!(parse_delimited::<i32, 3>("1,-2", ',').is_err());
+/// ```
+pub fn parse_delimited<'a, T, const N: usize>(
+    line: &'a str,
+    delimiter: char,
+) -> ParseResult<[T; N]>
+where
+    T: FromField<'a>,
+{
+    let mut values = Vec::with_capacity(N);
+    let mut rest = line;
+    let mut position = 0;
+    for index in 0..N {
+        let text = if index + 1 == N {
+            rest
+        } else if let Some((text, after)) = rest.split_once(delimiter) {
+            rest = after;
+            text
+        } else {
+            return Err(field_error(
+                line,
+                line.len(),
+                "",
+                index + 1,
+                ParseError::NoDelimiter(delimiter.into()),
+            ));
+        };
+
+        let value = T::from_text(text).map_err(|source| {
+            field_error(line, position, text, index, source)
+        })?;
+        values.push(value);
+        position += text.len() + delimiter.len_utf8();
+    }
+
+    // the vector was filled with a value per field
+    Ok(values
+        .try_into()
+        .unwrap_or_else(|_| unreachable!("expected {N} values")))
+}
+
+/// Extract every integer in a line, ignoring the text around them.
+///
+/// An integer is a run of ASCII digits, with a leading `-` when it doesn't
+/// follow a letter or digit, so `x=-3` holds `-3` but the range `1-5` holds
+/// `1` and `5`. Words without digits are ignored, but letters touching an
+/// integer are unexpected, so `x6` is an error rather than `6`.
+///
+/// # Errors
+///
+/// If an integer doesn't fit the type (or is negative for an unsigned type),
+/// or letters touch it, a [`ParseError::ParseInt`] error is returned, wrapped
+/// in a [`ParseError::InvalidField`] counting the integers found, then a
+/// [`ParseError::InvalidColumn`] spanning the integer and its letters.
+///
+/// # Examples
+///
+/// ```ignore
+/// use crate::util::parse::extract_integers;
+///
+/// let numbers = extract_integers::<i64>("Button A: X+94, Y=-34")?;
+/// assert_eq!(numbers, vec![94, -34]);
+/// assert!(extract_integers::<i64>("Button A: X+9b4").is_err());
+/// ```
+pub fn extract_integers<T>(line: &str) -> ParseResult<Vec<T>>
+where
+    T: FromStr<Err = ParseIntError>,
+{
+    let bytes = line.as_bytes();
+    let mut integers = Vec::new();
+    let mut index = 0;
+    while index < bytes.len() {
+        let is_sign = bytes[index] == b'-'
+            && bytes.get(index + 1).is_some_and(u8::is_ascii_digit)
+            && (index == 0 || !bytes[index - 1].is_ascii_alphanumeric());
+        if !is_sign && !bytes[index].is_ascii_alphanumeric() {
+            index += 1;
+            continue;
+        }
+
+        // take the whole word, so letters touching digits are caught
+        let start = index;
+        index += 1;
+        while bytes.get(index).is_some_and(u8::is_ascii_alphanumeric) {
+            index += 1;
+        }
+        // the word is ASCII, so slicing is on character boundaries
+        let text = &line[start..index];
+        if !text.bytes().any(|byte| byte.is_ascii_digit()) {
+            continue;
+        }
+        let integer = text.parse().map_err(|source| {
+            field_error(
+                line,
+                start,
+                text,
+                integers.len(),
+                ParseError::parse_int_from_str(text, source),
+            )
+        })?;
+        integers.push(integer);
+    }
+    Ok(integers)
+}
+
 #[cfg(test)]
 mod tests {
     use nalgebra::Matrix4x3;
@@ -384,6 +677,15 @@ mod tests {
         match result.unwrap_err() {
             ParseError::InvalidLine { line, source } => {
                 assert_eq!(line, 4, "expected failure on line 4");
+                let ParseError::InvalidColumn {
+                    column,
+                    width,
+                    source,
+                } = *source
+                else {
+                    panic!("unexpected source error type: {source:?}");
+                };
+                assert_eq!((column, width), (3, 1));
                 match *source {
                     ParseError::LineLength { expected, actual } => {
                         assert_eq!(expected, 3);
@@ -418,6 +720,15 @@ mod tests {
         match result.unwrap_err() {
             ParseError::InvalidLine { line, source } => {
                 assert_eq!(line, 5, "expected failure on line 5");
+                let ParseError::InvalidColumn {
+                    column,
+                    width,
+                    source,
+                } = *source
+                else {
+                    panic!("unexpected source error type: {source:?}");
+                };
+                assert_eq!((column, width), (2, 1));
                 match *source {
                     ParseError::ParseChar(character) => {
                         assert_eq!(character, 'b');
@@ -434,4 +745,163 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn parse_chunks_gives_offsets_and_numbers() -> ParseResult<()> {
+        let input = "a\nb\n\nc\n\nd\ne\n";
+        let [first, second, third] =
+            parse_chunks(input, ["first", "second", "third"])?;
+        assert_eq!(
+            first,
+            Chunk {
+                text: "a\nb",
+                offset: 0,
+                number: 1
+            }
+        );
+        assert_eq!(
+            second,
+            Chunk {
+                text: "c",
+                offset: 3,
+                number: 2
+            }
+        );
+        assert_eq!(
+            third,
+            Chunk {
+                text: "d\ne\n",
+                offset: 5,
+                number: 3
+            }
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn parse_chunks_numbers_lines_in_full_input() {
+        let input = "1\n2\n\n3\nbad\n";
+        let result =
+            parse_chunks(input, ["first", "second"]).and_then(|[_, second]| {
+                second
+                    .parse_lines(|line| {
+                        line.parse::<u32>().map_err(|source| {
+                            ParseError::parse_int_from_str(line, source)
+                        })
+                    })
+                    .collect::<ParseResult<Vec<_>>>()
+            });
+        match result {
+            Err(ParseError::InvalidLine { line, .. }) => {
+                assert_eq!(line, 5, "expected failure on line 5");
+            }
+            other => panic!("unexpected result: {other:?}"),
+        }
+    }
+
+    #[test]
+    fn parse_chunks_creates_missing_delimiter_error() {
+        let result = parse_chunks("1\n2\n", ["first", "second"]);
+        match result {
+            Err(ParseError::NoChunkDelimiter(delimiter)) => {
+                assert_eq!(delimiter, "\n\n");
+            }
+            other => panic!("unexpected result: {other:?}"),
+        }
+    }
+
+    #[test]
+    fn parse_chunks_creates_empty_chunk_error() {
+        let result = parse_chunks("1\n\n", ["numbers", "letters"]);
+        match result {
+            Err(ParseError::EmptyChunk {
+                chunk_number,
+                description,
+            }) => {
+                assert_eq!(chunk_number, 2);
+                assert_eq!(description, "letters");
+            }
+            other => panic!("unexpected result: {other:?}"),
+        }
+    }
+
+    #[test]
+    fn parse_delimited_successfully() -> ParseResult<()> {
+        let [x, y, z] = parse_delimited::<i32, 3>("1,-2,30", ',')?;
+        assert_eq!((x, y, z), (1, -2, 30));
+        let [low, high] = parse_delimited::<u64, 2>("3-17", '-')?;
+        assert_eq!((low, high), (3, 17));
+        Ok(())
+    }
+
+    #[test]
+    fn parse_delimited_locates_invalid_field() {
+        let result = parse_delimited::<u32, 3>("1,2x,3", ',');
+        match result {
+            Err(ParseError::InvalidColumn {
+                column,
+                width,
+                source,
+            }) => {
+                assert_eq!((column, width), (3, 2));
+                match *source {
+                    ParseError::InvalidField { field, source } => {
+                        assert_eq!(field, 2);
+                        assert!(matches!(*source, ParseError::ParseInt { .. }));
+                    }
+                    other => panic!("unexpected source error: {other:?}"),
+                }
+            }
+            other => panic!("unexpected result: {other:?}"),
+        }
+    }
+
+    #[test]
+    fn parse_delimited_locates_missing_field() {
+        let result = parse_delimited::<u32, 3>("1,2", ',');
+        match result {
+            Err(ParseError::InvalidColumn { column, source, .. }) => {
+                assert_eq!(column, 4);
+                match *source {
+                    ParseError::InvalidField { field, source } => {
+                        assert_eq!(field, 3);
+                        assert!(matches!(*source, ParseError::NoDelimiter(_)));
+                    }
+                    other => panic!("unexpected source error: {other:?}"),
+                }
+            }
+            other => panic!("unexpected result: {other:?}"),
+        }
+    }
+
+    #[test]
+    fn extract_integers_finds_signed_integers() -> ParseResult<()> {
+        let numbers = extract_integers::<i64>("Button A: X+94, Y=-34")?;
+        assert_eq!(numbers, vec![94, -34]);
+        let numbers = extract_integers::<i32>("-1-5 and 7")?;
+        assert_eq!(numbers, vec![-1, 5, 7]);
+        Ok(())
+    }
+
+    #[test]
+    fn extract_integers_locates_overflow() {
+        let result = extract_integers::<u8>("1 2 300");
+        let error = ParseError::invalid_line_from_one_based(
+            1,
+            result.expect_err("expected parse to fail"),
+        );
+        let span = error.location().expect("expected a location");
+        assert_eq!((span.column, span.width), (Some(5), 3));
+    }
+
+    #[test]
+    fn extract_integers_locates_letters_touching_integer() {
+        let result = extract_integers::<i64>("Button A: X+9b4");
+        let error = ParseError::invalid_line_from_one_based(
+            1,
+            result.expect_err("expected parse to fail"),
+        );
+        let span = error.location().expect("expected a location");
+        assert_eq!((span.column, span.width), (Some(13), 3));
+    }
 }
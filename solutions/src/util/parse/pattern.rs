@@ -0,0 +1,668 @@
+//! A declarative parser for lines of common formats.
+//!
+//! A [`Pattern`] is literal text with typed fields in braces, like
+//! `"{u64}-{u64}"` for a range or `"{str}: {list(str, ' ')}"` for a name
+//! with a list of names:
+//!
+//! - `{str}`: any non-empty text.
+//! - `{u8}` to `{u128}`, `{usize}`, `{i8}` to `{i128}`, `{isize}`: integers.
+//! - `{f32}`, `{f64}`: floats.
+//! - `{list(TYPE, 'SEPARATOR')}`: values of a type, split by a separator.
+//! - `{{` and `}}`: literal braces.
+//!
+//! A field matches text up to the first occurrence of the literal after it,
+//! or the rest of the line if it's last, so fields must be separated by
+//! literals. Parsing errors are wrapped in a
+//! [`ParseError::InvalidColumn`] spanning the text that failed, instead of
+//! panicking on malformed lines. Parsing fields into values of other types
+//! than the pattern's is a [`ParseError::PatternMismatch`].
+
+use std::fmt;
+
+use aoc_framework::{ParseError, ParseResult};
+
+use super::column_error;
+
+/// The names of value types that fields can have.
+const VALUE_TYPES: [&str; 15] = [
+    "str", "u8", "u16", "u32", "u64", "u128", "usize", "i8", "i16", "i32",
+    "i64", "i128", "isize", "f32", "f64",
+];
+
+/// The type of a pattern field.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum FieldType {
+    /// A single value of a named type.
+    Value(String),
+    /// A list of values of a named type, split by a separator.
+    List {
+        /// The name of the type of values.
+        element: String,
+        /// The separator between values.
+        separator: String,
+    },
+}
+
+impl FieldType {
+    /// Parse a field type from the text between a field's braces.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the field type is malformed or unknown.
+    fn from_spec(spec: &str, pattern: &str) -> Self {
+        let check = |name: &str| {
+            assert!(
+                VALUE_TYPES.contains(&name),
+                "unknown field type {name:?} in pattern {pattern:?}"
+            );
+        };
+
+        let Some(arguments) = spec
+            .strip_prefix("list(")
+            .and_then(|arguments| arguments.strip_suffix(')'))
+        else {
+            check(spec);
+            return Self::Value(spec.into());
+        };
+        let separator = arguments
+            .split_once(',')
+            .map(|(element, separator)| (element.trim(), separator.trim()))
+            .and_then(|(element, separator)| {
+                let separator =
+                    separator.strip_prefix('\'')?.strip_suffix('\'')?;
+                Some((element, separator))
+            })
+            .filter(|(_, separator)| !separator.is_empty());
+        let Some((element, separator)) = separator else {
+            panic!(
+                "expected list field like {{list(u32, ',')}}, got {{{spec}}} \
+                in pattern {pattern:?}"
+            );
+        };
+        check(element);
+        Self::List {
+            element: element.into(),
+            separator: separator.into(),
+        }
+    }
+}
+
+impl fmt::Display for FieldType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Value(name) => write!(f, "{{{name}}}"),
+            Self::List { element, separator } => {
+                write!(f, "{{list({element}, '{separator}')}}")
+            }
+        }
+    }
+}
+
+/// A segment of a pattern.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Segment {
+    /// Text that must match exactly.
+    Literal(String),
+    /// A typed field.
+    Field(FieldType),
+}
+
+/// A compiled line pattern of literal text and typed fields.
+///
+/// See the [module documentation][self] for the pattern syntax.
+///
+/// # Examples
+///
+/// ```ignore
+/// use crate::util::parse::pattern::Pattern;
+///
+/// let pattern = Pattern::new("{str}: {list(u32, ',')}");
+/// let (name, values): (&str, Vec<u32>) = pattern.parse("abc: 1,2,3")?;
+/// assert_eq!(name, "abc");
+/// assert_eq!(values, vec![1, 2, 3]);
+///
+/// // errors locate the failing text
+/// let error = pattern.parse::<(&str, Vec<u32>)>("abc: 1,x,3").unwrap_err();
+/// assert!(matches!(error, ParseError::InvalidColumn { column: 8, .. }));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Pattern {
+    /// The segments of the pattern, with no adjacent fields.
+    segments: Vec<Segment>,
+}
+
+impl Pattern {
+    /// Compile a pattern.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the pattern is malformed: a field type is unknown, braces
+    /// are unmatched, or fields aren't separated by literal text.
+    #[must_use]
+    pub fn new(pattern: &str) -> Self {
+        let mut segments = Vec::new();
+        let mut literal = String::new();
+        let mut rest = pattern;
+        while let Some(c) = rest.chars().next() {
+            if let Some(after) =
+                rest.strip_prefix("{{").or_else(|| rest.strip_prefix("}}"))
+            {
+                literal.push(c);
+                rest = after;
+                continue;
+            }
+            match c {
+                '{' => {
+                    let Some(end) = rest.find('}') else {
+                        panic!("unclosed field in pattern {pattern:?}");
+                    };
+                    if !literal.is_empty() {
+                        segments.push(Segment::Literal(literal.clone()));
+                        literal.clear();
+                    }
+                    assert!(
+                        !matches!(segments.last(), Some(Segment::Field(_))),
+                        "fields must be separated by literal text in \
+                        pattern {pattern:?}"
+                    );
+                    segments.push(Segment::Field(FieldType::from_spec(
+                        &rest[1..end],
+                        pattern,
+                    )));
+                    rest = &rest[end + 1..];
+                }
+                '}' => panic!("unmatched '}}' in pattern {pattern:?}"),
+                _ => {
+                    literal.push(c);
+                    rest = &rest[c.len_utf8()..];
+                }
+            }
+        }
+        if !literal.is_empty() {
+            segments.push(Segment::Literal(literal));
+        }
+        Self { segments }
+    }
+
+    /// Match a line against the pattern, capturing the text of fields.
+    ///
+    /// # Errors
+    ///
+    /// If literal text is missing, a [`ParseError::NoDelimiter`] is returned.
+    /// If text is left after the pattern, a [`ParseError::ParseString`] of
+    /// the text is returned. Either is wrapped in a
+    /// [`ParseError::InvalidColumn`] of where the text was expected or found.
+    pub fn captures<'a>(&self, line: &'a str) -> ParseResult<Captures<'_, 'a>> {
+        let mut fields = Vec::new();
+        let mut position = 0;
+        let mut segments = self.segments.iter().peekable();
+        while let Some(segment) = segments.next() {
+            let rest = &line[position..];
+            match segment {
+                Segment::Literal(literal) => {
+                    if !rest.starts_with(literal.as_str()) {
+                        return Err(column_error(
+                            line,
+                            position,
+                            literal,
+                            ParseError::NoDelimiter(literal.clone()),
+                        ));
+                    }
+                    position += literal.len();
+                }
+                Segment::Field(field_type) => {
+                    let length = match segments.peek() {
+                        Some(Segment::Literal(next)) => {
+                            rest.find(next.as_str()).ok_or_else(|| {
+                                column_error(
+                                    line,
+                                    line.len(),
+                                    "",
+                                    ParseError::NoDelimiter(next.clone()),
+                                )
+                            })?
+                        }
+                        _ => rest.len(),
+                    };
+                    fields.push(Field {
+                        kind: field_type,
+                        text: &rest[..length],
+                        start: position,
+                        line,
+                    });
+                    position += length;
+                }
+            }
+        }
+
+        let rest = &line[position..];
+        if !rest.is_empty() {
+            return Err(column_error(
+                line,
+                position,
+                rest,
+                ParseError::ParseString(rest.into()),
+            ));
+        }
+        Ok(Captures { fields })
+    }
+
+    /// Parse a line into a tuple of its fields' values.
+    ///
+    /// # Errors
+    ///
+    /// If the line doesn't match the pattern or a field's value fails to
+    /// parse, an error is returned, wrapped in a
+    /// [`ParseError::InvalidColumn`] of the failing text. If the tuple
+    /// doesn't have a value per field of the pattern, or a value's type
+    /// doesn't match its field's type, a [`ParseError::PatternMismatch`] is
+    /// returned.
+    pub fn parse<'a, T: FromFields<'a>>(
+        &self,
+        line: &'a str,
+    ) -> ParseResult<T> {
+        T::from_fields(&self.captures(line)?)
+    }
+
+    /// Parse each item of text split by a separator into a tuple of its
+    /// fields' values.
+    ///
+    /// Errors are wrapped in a [`ParseError::InvalidColumn`] of the item, so
+    /// their columns are relative to the whole text.
+    ///
+    /// # Errors
+    ///
+    /// If an item fails to parse, its error is returned, like
+    /// [`Pattern::parse`].
+    pub fn parse_separated<'a, T: FromFields<'a>>(
+        &self,
+        text: &'a str,
+        separator: &str,
+    ) -> ParseResult<Vec<T>> {
+        let mut start = 0;
+        text.split(separator)
+            .map(|item| {
+                let item_start = start;
+                start += item.len() + separator.len();
+                self.parse(item).map_err(|source| {
+                    column_error(text, item_start, item, source)
+                })
+            })
+            .collect()
+    }
+}
+
+/// The text of a field captured from a line.
+#[derive(Debug, Clone, Copy)]
+pub struct Field<'p, 'a> {
+    /// The type of the field in the pattern.
+    kind: &'p FieldType,
+    /// The captured text.
+    text: &'a str,
+    /// The byte position of the text in the line.
+    start: usize,
+    /// The whole line.
+    line: &'a str,
+}
+
+impl<'a> Field<'_, 'a> {
+    /// Wrap an error in a [`ParseError::InvalidColumn`] spanning text at a
+    /// byte offset into the field.
+    fn error(
+        &self,
+        offset: usize,
+        text: &str,
+        source: ParseError,
+    ) -> ParseError {
+        column_error(self.line, self.start + offset, text, source)
+    }
+
+    /// Create an error for parsing the field as a type it doesn't have,
+    /// wrapped in a [`ParseError::InvalidColumn`] of the field.
+    fn mismatch(&self, expected: &str) -> ParseError {
+        self.error(
+            0,
+            self.text,
+            ParseError::PatternMismatch(format!(
+                "can't parse {} field as {expected}",
+                self.kind
+            )),
+        )
+    }
+
+    /// Parse the captured text with a closure.
+    ///
+    /// Errors are wrapped in a [`ParseError::InvalidColumn`] of the field,
+    /// so the columns of errors from the closure can be relative to the
+    /// field's text.
+    ///
+    /// # Errors
+    ///
+    /// If the closure fails, its error is returned.
+    pub fn parse_with<T, F>(&self, parser: F) -> ParseResult<T>
+    where
+        F: FnOnce(&'a str) -> ParseResult<T>,
+    {
+        parser(self.text).map_err(|source| self.error(0, self.text, source))
+    }
+}
+
+/// The fields captured from a line by a [`Pattern`].
+#[derive(Debug, Clone)]
+pub struct Captures<'p, 'a> {
+    /// The fields in order of the pattern.
+    fields: Vec<Field<'p, 'a>>,
+}
+
+impl<'p, 'a> Captures<'p, 'a> {
+    /// Get a field by its index in the pattern.
+    ///
+    /// # Errors
+    ///
+    /// If the pattern has no field at the index, a
+    /// [`ParseError::PatternMismatch`] is returned.
+    pub fn field(&self, index: usize) -> ParseResult<Field<'p, 'a>> {
+        self.fields.get(index).copied().ok_or_else(|| {
+            ParseError::PatternMismatch(format!(
+                "pattern has {} fields, no field {index}",
+                self.fields.len()
+            ))
+        })
+    }
+
+    /// Parse the value of a field by its index in the pattern.
+    ///
+    /// # Errors
+    ///
+    /// If the value fails to parse, an error is returned, wrapped in a
+    /// [`ParseError::InvalidColumn`] of the failing text. If the pattern has
+    /// no field at the index, or the value's type doesn't match the field's
+    /// type, a [`ParseError::PatternMismatch`] is returned.
+    pub fn get<T: FieldValue<'a>>(&self, index: usize) -> ParseResult<T> {
+        T::from_field(&self.field(index)?)
+    }
+}
+
+/// A type that parses from the text of a single value field.
+pub trait FromField<'a>: Sized {
+    /// The name of the field type this parses from.
+    const FIELD_TYPE: &'static str;
+
+    /// Parse a value from text.
+    ///
+    /// # Errors
+    ///
+    /// If the text isn't a valid value, an error is returned.
+    fn from_text(text: &'a str) -> ParseResult<Self>;
+}
+
+impl<'a> FromField<'a> for &'a str {
+    const FIELD_TYPE: &'static str = "str";
+
+    fn from_text(text: &'a str) -> ParseResult<Self> {
+        if text.is_empty() {
+            Err(ParseError::ParseString(text.into()))
+        } else {
+            Ok(text)
+        }
+    }
+}
+
+impl FromField<'_> for String {
+    const FIELD_TYPE: &'static str = "str";
+
+    fn from_text(text: &str) -> ParseResult<Self> {
+        <&str>::from_text(text).map(Self::from)
+    }
+}
+
+/// Implement [`FromField`] for integer types.
+macro_rules! impl_from_field_int {
+    ($($int:ty),*) => {
+        $(
+            impl FromField<'_> for $int {
+                const FIELD_TYPE: &'static str = stringify!($int);
+
+                fn from_text(text: &str) -> ParseResult<Self> {
+                    text.parse().map_err(|source| {
+                        ParseError::parse_int_from_str(text, source)
+                    })
+                }
+            }
+        )*
+    };
+}
+
+impl_from_field_int!(
+    u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize
+);
+
+/// Implement [`FromField`] for float types.
+macro_rules! impl_from_field_float {
+    ($($float:ty),*) => {
+        $(
+            impl FromField<'_> for $float {
+                const FIELD_TYPE: &'static str = stringify!($float);
+
+                fn from_text(text: &str) -> ParseResult<Self> {
+                    text.parse().map_err(|source| {
+                        ParseError::parse_float_from_str(text, source)
+                    })
+                }
+            }
+        )*
+    };
+}
+
+impl_from_field_float!(f32, f64);
+
+/// A type that parses from a field: a single value, or a [`Vec`] of a list
+/// field's values.
+pub trait FieldValue<'a>: Sized {
+    /// Parse from a field.
+    ///
+    /// # Errors
+    ///
+    /// If the value fails to parse, an error is returned, wrapped in a
+    /// [`ParseError::InvalidColumn`] of the failing text. If the type doesn't
+    /// match the field's type, the error is a
+    /// [`ParseError::PatternMismatch`].
+    fn from_field(field: &Field<'_, 'a>) -> ParseResult<Self>;
+}
+
+impl<'a, T: FromField<'a>> FieldValue<'a> for T {
+    fn from_field(field: &Field<'_, 'a>) -> ParseResult<Self> {
+        let matches_type = matches!(
+            field.kind,
+            FieldType::Value(name) if name == T::FIELD_TYPE
+        );
+        if !matches_type {
+            return Err(field.mismatch(T::FIELD_TYPE));
+        }
+        T::from_text(field.text)
+            .map_err(|source| field.error(0, field.text, source))
+    }
+}
+
+impl<'a, T: FromField<'a>> FieldValue<'a> for Vec<T> {
+    fn from_field(field: &Field<'_, 'a>) -> ParseResult<Self> {
+        let expected = format!("a list of {}", T::FIELD_TYPE);
+        let FieldType::List { element, separator } = field.kind else {
+            return Err(field.mismatch(&expected));
+        };
+        if element != T::FIELD_TYPE {
+            return Err(field.mismatch(&expected));
+        }
+
+        let mut start = 0;
+        field
+            .text
+            .split(separator.as_str())
+            .map(|item| {
+                let item_start = start;
+                start += item.len() + separator.len();
+                T::from_text(item)
+                    .map_err(|source| field.error(item_start, item, source))
+            })
+            .collect()
+    }
+}
+
+/// A type that parses from every field captured by a pattern: a tuple of a
+/// value per field.
+pub trait FromFields<'a>: Sized {
+    /// Parse from captured fields.
+    ///
+    /// # Errors
+    ///
+    /// If a value fails to parse, an error is returned. If there isn't a
+    /// value per field, or a value's type doesn't match its field's type, a
+    /// [`ParseError::PatternMismatch`] is returned.
+    fn from_fields(captures: &Captures<'_, 'a>) -> ParseResult<Self>;
+}
+
+/// Implement [`FromFields`] for a tuple of field values.
+macro_rules! impl_from_fields {
+    ($count:literal => $($value:ident $index:tt),+) => {
+        impl<'a, $($value: FieldValue<'a>),+> FromFields<'a> for ($($value,)+) {
+            fn from_fields(captures: &Captures<'_, 'a>) -> ParseResult<Self> {
+                if captures.fields.len() != $count {
+                    return Err(ParseError::PatternMismatch(format!(
+                        "pattern has {} fields, expected {}",
+                        captures.fields.len(),
+                        $count
+                    )));
+                }
+                Ok(($(captures.get::<$value>($index)?,)+))
+            }
+        }
+    };
+}
+
+impl_from_fields!(1 => A 0);
+impl_from_fields!(2 => A 0, B 1);
+impl_from_fields!(3 => A 0, B 1, C 2);
+impl_from_fields!(4 => A 0, B 1, C 2, D 3);
+impl_from_fields!(5 => A 0, B 1, C 2, D 3, E 4);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_typed_fields() -> ParseResult<()> {
+        let (low, high): (u64, u64) =
+            Pattern::new("{u64}-{u64}").parse("3-17")?;
+        assert_eq!((low, high), (3, 17));
+
+        let pattern = Pattern::new("{str}: {list(str, ' ')}");
+        let (name, outputs): (&str, Vec<String>) =
+            pattern.parse("you: bbb ccc")?;
+        assert_eq!(name, "you");
+        assert_eq!(outputs, ["bbb", "ccc"]);
+
+        let pattern = Pattern::new("[{str}] {{{list(i32, ',')}}}");
+        let (lights, values): (&str, Vec<i32>) =
+            pattern.parse("[.##.] {3,-5,4}")?;
+        assert_eq!(lights, ".##.");
+        assert_eq!(values, [3, -5, 4]);
+        Ok(())
+    }
+
+    #[test]
+    fn locates_invalid_field_value() {
+        let pattern = Pattern::new("{str}: {list(u32, ',')}");
+        let result = pattern.parse::<(&str, Vec<u32>)>("abc: 1,x2,3");
+        match result {
+            Err(ParseError::InvalidColumn {
+                column,
+                width,
+                source,
+            }) => {
+                assert_eq!((column, width), (8, 2));
+                assert!(matches!(*source, ParseError::ParseInt { .. }));
+            }
+            other => panic!("unexpected result: {other:?}"),
+        }
+    }
+
+    #[test]
+    fn locates_missing_and_trailing_text() {
+        let pattern = Pattern::new("{u32},{u32}");
+        match pattern.parse::<(u32, u32)>("12;34") {
+            Err(ParseError::InvalidColumn { column, source, .. }) => {
+                assert_eq!(column, 6);
+                assert!(matches!(*source, ParseError::NoDelimiter(_)));
+            }
+            other => panic!("unexpected result: {other:?}"),
+        }
+
+        let pattern = Pattern::new("({u32})");
+        match pattern.parse::<(u32,)>("(12)ab") {
+            Err(ParseError::InvalidColumn {
+                column,
+                width,
+                source,
+            }) => {
+                assert_eq!((column, width), (5, 2));
+                assert!(matches!(*source, ParseError::ParseString(_)));
+            }
+            other => panic!("unexpected result: {other:?}"),
+        }
+    }
+
+    #[test]
+    fn parses_separated_items_with_nested_columns() {
+        let pattern = Pattern::new("{u64}-{u64}");
+        let ranges: ParseResult<Vec<(u64, u64)>> =
+            pattern.parse_separated("1-2,30-4x", ",");
+        let error = ParseError::invalid_line_from_one_based(
+            1,
+            ranges.expect_err("expected parse to fail"),
+        );
+        let span = error.location().expect("expected a location");
+        assert_eq!((span.column, span.width), (Some(8), 2));
+    }
+
+    #[test]
+    #[should_panic(expected = "fields must be separated")]
+    fn panics_on_adjacent_fields() {
+        let _ = Pattern::new("{u32}{u32}");
+    }
+
+    #[test]
+    fn errors_on_mismatched_types() {
+        let mismatch = |result: ParseResult<_>| match result {
+            Err(ParseError::InvalidColumn { source, .. }) => match *source {
+                ParseError::PatternMismatch(message) => message,
+                other => panic!("unexpected source: {other:?}"),
+            },
+            Err(ParseError::PatternMismatch(message)) => message,
+            Ok(()) => panic!("expected parse to fail"),
+            Err(other) => panic!("unexpected error: {other:?}"),
+        };
+
+        let pattern = Pattern::new("{u32}");
+        let result = pattern.parse::<(&str,)>("1").map(|_| ());
+        assert_eq!(mismatch(result), "can't parse {u32} field as str");
+        let result = pattern.parse::<(Vec<u32>,)>("1").map(|_| ());
+        assert_eq!(
+            mismatch(result),
+            "can't parse {u32} field as a list of u32"
+        );
+        let result = pattern.parse::<(u32, u32)>("1").map(|_| ());
+        assert_eq!(mismatch(result), "pattern has 1 fields, expected 2");
+        let result = pattern
+            .captures("1")
+            .and_then(|captures| captures.field(1))
+            .map(|_| ());
+        assert_eq!(mismatch(result), "pattern has 1 fields, no field 1");
+
+        let pattern = Pattern::new("{list(u16, ',')}");
+        let result = pattern.parse::<(Vec<u32>,)>("1,2").map(|_| ());
+        assert_eq!(
+            mismatch(result),
+            "can't parse {list(u16, ',')} field as a list of u32"
+        );
+    }
+}
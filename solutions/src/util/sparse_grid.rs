@@ -0,0 +1,239 @@
+//! Utility type for unbounded grids with points, storing only set values.
+
+use std::collections::HashMap;
+
+use nalgebra::DMatrix;
+
+use crate::util::matrix::{MatrixPoint, MatrixPointAccess};
+
+/// The inclusive bounds of points in a [`SparseGrid`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Bounds {
+    /// The point with the smallest components.
+    min: MatrixPoint,
+    /// The point with the largest components.
+    max: MatrixPoint,
+}
+
+impl Bounds {
+    /// Create bounds holding only a point.
+    fn from_point(point: MatrixPoint) -> Self {
+        Self {
+            min: point,
+            max: point,
+        }
+    }
+
+    /// Grow the bounds to include a point.
+    fn include(&mut self, point: MatrixPoint) {
+        self.min = self.min.inf(&point);
+        self.max = self.max.sup(&point);
+    }
+}
+
+/// An unbounded grid of values indexed by points, storing values in a hash
+/// map so huge or growing grids only cost memory for the values set.
+///
+/// Every point holds a value: points without a set value hold the grid's
+/// default value.
+///
+/// The grid tracks the bounds of the points set, which grow as values are set
+/// and are covered when iterating [`points`][MatrixPointAccess::points].
+///
+/// # Examples
+///
+/// ```ignore
+/// use crate::util::matrix::{MatrixPoint, MatrixPointAccess};
+/// use crate::util::parse::parse_grid;
+/// use crate::util::sparse_grid::SparseGrid;
+///
+/// let matrix = parse_grid("#.\n.#\n", |_point, character| Ok(character))?;
+/// let mut grid = SparseGrid::from_matrix(&matrix, '.');
+/// assert_eq!(grid.iter().count(), 2);
+///
+/// grid.insert(MatrixPoint::new(-5, 10), '#');
+/// assert_eq!(grid.get_at_point(MatrixPoint::new(100, 100)), Some(&'.'));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SparseGrid<T> {
+    /// The values set, by point.
+    values: HashMap<MatrixPoint, T>,
+    /// The value of points without a set value.
+    default: T,
+    /// The bounds of points set, if any have been.
+    bounds: Option<Bounds>,
+}
+
+impl<T> SparseGrid<T> {
+    /// Create an empty grid, where every point holds a default value.
+    #[must_use]
+    pub fn new(default: T) -> Self {
+        Self {
+            values: HashMap::new(),
+            default,
+            bounds: None,
+        }
+    }
+
+    /// Create a grid from a matrix, like one made by
+    /// [`parse_grid`][crate::util::parse::parse_grid], storing only the
+    /// values that aren't the default value.
+    ///
+    /// The bounds of the grid are the bounds of the matrix.
+    #[must_use]
+    pub fn from_matrix(matrix: &DMatrix<T>, default: T) -> Self
+    where
+        T: Clone + PartialEq,
+    {
+        let mut grid = Self::new(default);
+        for point in matrix.points() {
+            if let Some(value) = matrix.get_at_point(point)
+                && *value != grid.default
+            {
+                grid.insert(point, value.clone());
+            }
+        }
+        if let Some(last) = matrix.points().last() {
+            let mut bounds = Bounds::from_point(MatrixPoint::origin());
+            bounds.include(last);
+            grid.bounds = Some(bounds);
+        }
+        grid
+    }
+
+    /// Set the value at a point, growing the bounds to include it.
+    ///
+    /// Returns the value previously set at the point.
+    pub fn insert(&mut self, point: MatrixPoint, value: T) -> Option<T> {
+        self.include(point);
+        self.values.insert(point, value)
+    }
+
+    /// Unset the value at a point, so it holds the default value again.
+    ///
+    /// The bounds aren't shrunk, so iterating points still covers the point.
+    ///
+    /// Returns the value previously set at the point.
+    pub fn remove(&mut self, point: MatrixPoint) -> Option<T> {
+        self.values.remove(&point)
+    }
+
+    /// Get an iterator of the points and values set, in arbitrary order.
+    pub fn iter(&self) -> impl Iterator<Item = (MatrixPoint, &T)> + '_ {
+        self.values.iter().map(|(&point, value)| (point, value))
+    }
+
+    /// Grow the bounds to include a point.
+    fn include(&mut self, point: MatrixPoint) {
+        match &mut self.bounds {
+            Some(bounds) => bounds.include(point),
+            None => self.bounds = Some(Bounds::from_point(point)),
+        }
+    }
+}
+
+/// As the grid is unbounded, every point can index it, and points without a
+/// set value hold the default value. Mutably indexing such a point sets the
+/// default value there.
+///
+/// Iterating points covers the bounds of the grid, row by row.
+impl<T: Clone> MatrixPointAccess<T> for SparseGrid<T> {
+    fn contains_point(&self, _point: MatrixPoint) -> bool {
+        true
+    }
+
+    fn get_at_point(&self, point: MatrixPoint) -> Option<&T> {
+        Some(self.values.get(&point).unwrap_or(&self.default))
+    }
+
+    fn get_at_point_mut(&mut self, point: MatrixPoint) -> Option<&mut T> {
+        self.include(point);
+        Some(
+            self.values
+                .entry(point)
+                .or_insert_with(|| self.default.clone()),
+        )
+    }
+
+    fn points(&self) -> impl Iterator<Item = MatrixPoint> + '_ {
+        self.bounds.into_iter().flat_map(|Bounds { min, max }| {
+            (min.y..=max.y).flat_map(move |y| {
+                (min.x..=max.x).map(move |x| MatrixPoint::new(x, y))
+            })
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use super::*;
+
+    #[test]
+    fn get_at_point_returns_default_without_set_value() {
+        let mut grid = SparseGrid::new(0);
+        grid.insert(MatrixPoint::new(100_000, -3), 7);
+
+        assert_eq!(grid.get_at_point(MatrixPoint::new(100_000, -3)), Some(&7));
+        assert_eq!(grid.get_at_point(MatrixPoint::new(-20, 40)), Some(&0));
+        assert!(grid.contains_point(MatrixPoint::new(i32::MIN, i32::MAX)));
+    }
+
+    #[test]
+    fn get_at_point_mut_sets_default_value() {
+        let mut grid = SparseGrid::new(1);
+        let point = MatrixPoint::new(2, 3);
+        let value_ref = grid
+            .get_at_point_mut(point)
+            .expect("expected result for point");
+        *value_ref *= 5;
+
+        assert_eq!(grid.get_at_point(point), Some(&5));
+        assert_eq!(grid.iter().collect::<Vec<_>>(), [(point, &5)]);
+    }
+
+    #[test]
+    fn points_cover_bounds_of_set_values() {
+        let mut grid = SparseGrid::new(false);
+        assert_eq!(grid.points().count(), 0);
+
+        grid.insert(MatrixPoint::new(1, -1), true);
+        grid.insert(MatrixPoint::new(-1, 1), true);
+        let points: Vec<MatrixPoint> = grid.points().collect();
+        assert_eq!(points.len(), 9);
+        assert_eq!(points.first(), Some(&MatrixPoint::new(-1, -1)));
+        assert_eq!(points.last(), Some(&MatrixPoint::new(1, 1)));
+    }
+
+    #[test]
+    fn from_matrix_stores_values_that_are_not_default() {
+        let matrix =
+            DMatrix::from_row_slice(2, 3, &['#', '.', '.', '.', '.', '#']);
+        let grid = SparseGrid::from_matrix(&matrix, '.');
+
+        let set: HashSet<MatrixPoint> =
+            grid.iter().map(|(point, _)| point).collect();
+        assert_eq!(
+            set,
+            HashSet::from([MatrixPoint::new(0, 0), MatrixPoint::new(2, 1)])
+        );
+
+        let points: HashSet<MatrixPoint> = grid.points().collect();
+        let matrix_points: HashSet<MatrixPoint> = matrix.points().collect();
+        assert_eq!(points, matrix_points);
+    }
+
+    #[test]
+    fn remove_unsets_value_keeping_bounds() {
+        let mut grid = SparseGrid::new('.');
+        let point = MatrixPoint::new(4, -2);
+        grid.insert(point, '#');
+
+        assert_eq!(grid.remove(point), Some('#'));
+        assert_eq!(grid.remove(point), None);
+        assert_eq!(grid.get_at_point(point), Some(&'.'));
+        assert_eq!(grid.iter().count(), 0);
+        assert_eq!(grid.points().collect::<Vec<_>>(), [point]);
+    }
+}
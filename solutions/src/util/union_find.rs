@@ -0,0 +1,149 @@
+//! Utility type for tracking disjoint sets of elements, merged by union.
+
+/// A disjoint-set (union-find) forest over elements indexed `0..len`, tracking
+/// which component each element belongs to as components are merged.
+///
+/// Finding uses path compression and merging uses union by size, so operations
+/// take near-constant amortized time.
+///
+/// # Examples
+///
+/// ```ignore
+/// use crate::util::union_find::DisjointSet;
+///
+/// let mut set = DisjointSet::new(4);
+/// assert!(set.union(0, 1));
+/// assert!(!set.union(1, 0));
+/// assert_eq!(set.find(0), set.find(1));
+/// assert_eq!(set.component_count(), 3);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DisjointSet {
+    /// The parent of each element, with a component's root as its own parent.
+    parents: Vec<usize>,
+    /// The size of each component, by its root; other elements' sizes are
+    /// stale.
+    sizes: Vec<usize>,
+    /// The number of components.
+    components: usize,
+}
+
+impl DisjointSet {
+    /// Create a set where each of `len` elements is its own component.
+    #[must_use]
+    pub fn new(len: usize) -> Self {
+        Self {
+            parents: (0..len).collect(),
+            sizes: vec![1; len],
+            components: len,
+        }
+    }
+
+    /// Find the root element of an element's component.
+    ///
+    /// # Panics
+    ///
+    /// If the element is out of bounds.
+    pub fn find(&mut self, element: usize) -> usize {
+        let mut root = element;
+        while self.parents[root] != root {
+            root = self.parents[root];
+        }
+
+        // compress the path so later finds go straight to the root
+        let mut current = element;
+        while self.parents[current] != root {
+            let next = self.parents[current];
+            self.parents[current] = root;
+            current = next;
+        }
+        root
+    }
+
+    /// Merge the components of two elements, attaching the smaller component
+    /// to the larger.
+    ///
+    /// Returns whether the elements were in different components.
+    ///
+    /// # Panics
+    ///
+    /// If an element is out of bounds.
+    pub fn union(&mut self, a: usize, b: usize) -> bool {
+        let (a_root, b_root) = (self.find(a), self.find(b));
+        if a_root == b_root {
+            return false;
+        }
+
+        let (larger, smaller) = if self.sizes[a_root] < self.sizes[b_root] {
+            (b_root, a_root)
+        } else {
+            (a_root, b_root)
+        };
+        self.parents[smaller] = larger;
+        self.sizes[larger] += self.sizes[smaller];
+        self.components -= 1;
+        true
+    }
+
+    /// Get the number of components.
+    #[must_use]
+    pub fn component_count(&self) -> usize {
+        self.components
+    }
+
+    /// Get an iterator of the sizes of components, in arbitrary order.
+    pub fn component_sizes(&self) -> impl Iterator<Item = usize> + '_ {
+        self.parents
+            .iter()
+            .zip(&self.sizes)
+            .enumerate()
+            .filter(|&(element, (&parent, _))| element == parent)
+            .map(|(_, (_, &size))| size)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn union_merges_components() {
+        let mut set = DisjointSet::new(5);
+        assert_eq!(set.component_count(), 5);
+
+        assert!(set.union(0, 1));
+        assert!(set.union(3, 4));
+        assert!(set.union(1, 4));
+        assert!(!set.union(0, 3), "expected elements already merged");
+
+        assert_eq!(set.component_count(), 2);
+        assert_eq!(set.find(0), set.find(3));
+        assert_ne!(set.find(2), set.find(0));
+    }
+
+    #[test]
+    fn component_sizes_track_merges() {
+        let mut set = DisjointSet::new(6);
+        set.union(0, 1);
+        set.union(2, 1);
+        set.union(4, 5);
+
+        let mut sizes: Vec<usize> = set.component_sizes().collect();
+        sizes.sort_unstable();
+        assert_eq!(sizes, vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn find_compresses_paths() {
+        let mut set = DisjointSet::new(4);
+        set.union(0, 1);
+        set.union(2, 3);
+        set.union(1, 3);
+
+        let root = set.find(3);
+        for element in 0..4 {
+            assert_eq!(set.find(element), root);
+            assert_eq!(set.parents[element], root);
+        }
+    }
+}
@@ -0,0 +1,22 @@
+//! Checks of solutions against the example data files in `tests/examples/`.
+//!
+//! Adding an example case only needs an input file and its expected answers
+//! in the day's directory.
+
+use aoc_framework::test_examples;
+
+test_examples! {
+    // --- CHECK EXAMPLES HERE ---
+    day00 => solutions::day00::Day00,
+    day01 => solutions::day01::Day01,
+    day02 => solutions::day02::Day02,
+    day03 => solutions::day03::Day03,
+    day04 => solutions::day04::Day04,
+    day05 => solutions::day05::Day05,
+    day06 => solutions::day06::Day06,
+    day07 => solutions::day07::Day07,
+    day08 => solutions::day08::Day08,
+    day09 => solutions::day09::Day09,
+    day10 => solutions::day10::Day10,
+    day11 => solutions::day11::Day11,
+}
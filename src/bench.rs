@@ -0,0 +1,93 @@
+//! Formatting of benchmark reports.
+
+use std::fmt::Write;
+
+use aoc_framework::bench::BenchReport;
+
+use crate::format::format_duration;
+
+/// Format a table of statistics for each phase of a benchmark report.
+#[must_use]
+pub fn format_bench(report: &BenchReport) -> String {
+    const HEADERS: [&str; 7] =
+        ["Phase", "Runs", "Min", "Median", "Mean", "P95", "Stddev"];
+
+    // build rows of cells first to measure column widths
+    let rows: Vec<[String; 7]> = report
+        .phases
+        .iter()
+        .map(|phase| {
+            let stats = &phase.stats;
+            [
+                phase.phase.default_name().into(),
+                stats.runs.to_string(),
+                format_duration(stats.min),
+                format_duration(stats.median),
+                format_duration(stats.mean),
+                format_duration(stats.p95),
+                format_duration(stats.stddev),
+            ]
+        })
+        .collect();
+
+    let mut widths = HEADERS.map(str::len);
+    for row in &rows {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.chars().count());
+        }
+    }
+
+    let mut table = String::new();
+    let mut push_row = |cells: [&str; 7]| {
+        let line = cells
+            .iter()
+            .zip(widths)
+            .enumerate()
+            .map(|(index, (cell, width))| {
+                // left align the phase name, right align numbers
+                if index == 0 {
+                    format!("{cell:<width$}")
+                } else {
+                    format!("{cell:>width$}")
+                }
+            })
+            .collect::<Vec<_>>()
+            .join(" | ");
+        // writing to a string can't fail
+        let _ = writeln!(table, "{line}");
+    };
+
+    push_row(HEADERS);
+    for row in &rows {
+        push_row(row.each_ref().map(String::as_str));
+    }
+    table
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use aoc_framework::SolutionPart;
+    use aoc_framework::bench::BenchPhase;
+
+    use super::*;
+
+    #[test]
+    fn format_bench_aligns_columns() {
+        let mut report = BenchReport::default();
+        report.push(BenchPhase::Parse, &[Duration::from_micros(10)]);
+        report.push(
+            BenchPhase::Part(SolutionPart::Part1),
+            &[Duration::from_micros(1), Duration::from_micros(3)],
+        );
+
+        let table = format_bench(&report);
+        let expected = "\
+Phase  | Runs |       Min |    Median |      Mean |       P95 |   Stddev
+Parse  |    1 | 10.000 µs | 10.000 µs | 10.000 µs | 10.000 µs | 0.000 µs
+Part 1 |    2 |  1.000 µs |  2.000 µs |  2.000 µs |  3.000 µs | 1.000 µs
+";
+        assert_eq!(table, expected);
+    }
+}
@@ -0,0 +1,288 @@
+//! Verification of answers against stored expected answers.
+
+use std::collections::BTreeMap;
+use std::fmt::Display;
+use std::str::FromStr;
+use std::time::Duration;
+
+use aoc_framework::answers::AnswerTable;
+use aoc_framework::{OutputHandler, SolutionPart};
+use thiserror::Error;
+
+/// The expected answers of a day's parts.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct DayAnswers {
+    /// The expected answer of part 1, if known.
+    pub part1: Option<String>,
+    /// The expected answer of part 2, if known.
+    pub part2: Option<String>,
+}
+
+impl DayAnswers {
+    /// Get the expected answer of a part, if known.
+    #[must_use]
+    pub fn get(&self, part: SolutionPart) -> Option<&str> {
+        match part {
+            SolutionPart::Part1 => self.part1.as_deref(),
+            SolutionPart::Part2 => self.part2.as_deref(),
+        }
+    }
+}
+
+/// An error parsing [`ExpectedAnswers`].
+#[derive(Error, Debug)]
+pub enum AnswersError {
+    /// The file was not valid TOML of the expected shape.
+    #[error(transparent)]
+    Toml(#[from] toml::de::Error),
+
+    /// A table was not named for a day, like `day01`.
+    #[error("invalid day table {key:?}, expected a name like \"day01\"")]
+    InvalidKey {
+        /// The name of the table.
+        key: String,
+    },
+}
+
+/// Expected answers of days, parsed from a TOML file.
+///
+/// Each day is a table named `dayNN` with optional `part1` and `part2` keys,
+/// written as strings or integers:
+///
+/// ```toml
+/// [day01]
+/// part1 = 3
+/// part2 = "6"
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ExpectedAnswers(BTreeMap<u8, DayAnswers>);
+
+impl ExpectedAnswers {
+    /// Get the expected answers of a day, empty if the day has none.
+    #[must_use]
+    pub fn day(&self, day: u8) -> DayAnswers {
+        self.0.get(&day).cloned().unwrap_or_default()
+    }
+}
+
+impl FromStr for ExpectedAnswers {
+    type Err = AnswersError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let tables: BTreeMap<String, AnswerTable> = toml::from_str(s)?;
+
+        let mut days = BTreeMap::new();
+        for (key, table) in tables {
+            let day = key
+                .strip_prefix("day")
+                .and_then(|number| number.parse().ok())
+                .ok_or(AnswersError::InvalidKey { key })?;
+            days.insert(
+                day,
+                DayAnswers {
+                    part1: table.part1.map(String::from),
+                    part2: table.part2.map(String::from),
+                },
+            );
+        }
+        Ok(Self(days))
+    }
+}
+
+/// The verdict of checking a part's answer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CheckStatus {
+    /// The answer matched the expected answer.
+    Pass,
+    /// The answer did not match the expected answer.
+    Fail {
+        /// The expected answer.
+        expected: String,
+    },
+    /// There was no expected answer to check against.
+    Unknown,
+}
+
+/// The result of checking a part's answer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PartCheck {
+    /// The part that was checked.
+    pub part: SolutionPart,
+    /// The displayed answer of the part.
+    pub answer: String,
+    /// The verdict of the check.
+    pub status: CheckStatus,
+}
+
+/// Count the checks that failed.
+#[must_use]
+pub fn count_failed(checks: &[PartCheck]) -> usize {
+    checks
+        .iter()
+        .filter(|check| matches!(check.status, CheckStatus::Fail { .. }))
+        .count()
+}
+
+/// An output handler that checks answers against expected answers while
+/// forwarding events to another handler.
+///
+/// Answers are compared as displayed, ignoring surrounding whitespace.
+pub struct CheckingHandler<'a> {
+    /// The handler to forward events to.
+    inner: &'a mut dyn OutputHandler,
+    /// The expected answers of the day being run.
+    expected: DayAnswers,
+    /// The checks of parts output so far.
+    checks: Vec<PartCheck>,
+}
+
+impl<'a> CheckingHandler<'a> {
+    /// Construct a checking handler for a day's expected answers.
+    #[must_use]
+    pub fn new(inner: &'a mut dyn OutputHandler, expected: DayAnswers) -> Self {
+        Self {
+            inner,
+            expected,
+            checks: Vec::new(),
+        }
+    }
+
+    /// Finish checking, returning the checks of each part output.
+    #[must_use]
+    pub fn finish(self) -> Vec<PartCheck> {
+        self.checks
+    }
+
+    /// Check the answer of a part.
+    fn check_part(&mut self, part: SolutionPart, output: &dyn Display) {
+        let answer = output.to_string();
+        let status = match self.expected.get(part) {
+            Some(expected) if expected.trim() == answer.trim() => {
+                CheckStatus::Pass
+            }
+            Some(expected) => CheckStatus::Fail {
+                expected: expected.into(),
+            },
+            None => CheckStatus::Unknown,
+        };
+        self.checks.push(PartCheck {
+            part,
+            answer,
+            status,
+        });
+    }
+}
+
+impl OutputHandler for CheckingHandler<'_> {
+    fn solution_name(&mut self, name: &str) {
+        self.inner.solution_name(name);
+    }
+
+    fn parse_start(&mut self) {
+        self.inner.parse_start();
+    }
+
+    fn parse_end(&mut self) {
+        self.inner.parse_end();
+    }
+
+    fn parse_end_timed(&mut self, duration: Duration) {
+        self.inner.parse_end_timed(duration);
+    }
+
+    fn part_start(&mut self, part: SolutionPart) {
+        self.inner.part_start(part);
+    }
+
+    fn part_output(&mut self, part: SolutionPart, output: &dyn Display) {
+        self.check_part(part, output);
+        self.inner.part_output(part, output);
+    }
+
+    fn part_output_timed(
+        &mut self,
+        part: SolutionPart,
+        output: &dyn Display,
+        duration: Duration,
+    ) {
+        self.check_part(part, output);
+        self.inner.part_output_timed(part, output, duration);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_framework::testing::NullHandler;
+
+    use super::*;
+
+    #[test]
+    fn parses_answers_file() {
+        let answers: ExpectedAnswers = "
+            [day01]
+            part1 = 3
+            part2 = \"6\"
+
+            [day12]
+            part1 = \"abc\"
+        "
+        .parse()
+        .expect("expected to parse");
+
+        assert_eq!(
+            answers.day(1),
+            DayAnswers {
+                part1: Some("3".into()),
+                part2: Some("6".into()),
+            }
+        );
+        assert_eq!(answers.day(12).get(SolutionPart::Part1), Some("abc"));
+        assert_eq!(answers.day(12).get(SolutionPart::Part2), None);
+        assert_eq!(answers.day(2), DayAnswers::default());
+    }
+
+    #[test]
+    fn errors_on_invalid_day_table() {
+        let result = "[first]\npart1 = 1".parse::<ExpectedAnswers>();
+        match result {
+            Err(AnswersError::InvalidKey { key }) => assert_eq!(key, "first"),
+            other => panic!("unexpected result: {other:?}"),
+        }
+        let result = "[day01]\npart3 = 1".parse::<ExpectedAnswers>();
+        assert!(
+            matches!(result, Err(AnswersError::Toml(_))),
+            "unexpected result: {result:?}"
+        );
+    }
+
+    #[test]
+    fn checking_handler_marks_pass_fail_and_unknown() {
+        let mut inner = NullHandler;
+        let expected = DayAnswers {
+            part1: Some("3".into()),
+            part2: None,
+        };
+        let mut handler = CheckingHandler::new(&mut inner, expected.clone());
+        handler.part_output(SolutionPart::Part1, &3);
+        handler.part_output_timed(
+            SolutionPart::Part2,
+            &6,
+            Duration::from_micros(1),
+        );
+        let checks = handler.finish();
+        assert_eq!(checks[0].status, CheckStatus::Pass);
+        assert_eq!(checks[1].status, CheckStatus::Unknown);
+        assert_eq!(count_failed(&checks), 0);
+
+        let mut handler = CheckingHandler::new(&mut inner, expected);
+        handler.part_output(SolutionPart::Part1, &4);
+        let checks = handler.finish();
+        assert_eq!(
+            checks[0].status,
+            CheckStatus::Fail {
+                expected: "3".into()
+            }
+        );
+        assert_eq!(count_failed(&checks), 1);
+    }
+}
@@ -0,0 +1,364 @@
+//! A client for the Advent of Code site, to download inputs and submit
+//! answers.
+//!
+//! Requests are authenticated with the session cookie of a logged in user,
+//! identify the tool with a User-Agent, and are throttled to go easy on the
+//! site, following its automation rules.
+
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+use std::{env, fs, io, thread};
+
+use aoc_framework::SolutionPart;
+use thiserror::Error;
+use ureq::Agent;
+
+/// The puzzle year of this repository's solutions.
+pub const YEAR: u16 = 2025;
+
+/// The default base URL of the Advent of Code site.
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+/// The environment variable holding a session token.
+pub const SESSION_ENV: &str = "AOC_SESSION";
+
+/// The User-Agent sent with requests, pointing to the repository so site
+/// maintainers know who's automating requests.
+const USER_AGENT: &str = concat!(
+    env!("CARGO_PKG_NAME"),
+    "/",
+    env!("CARGO_PKG_VERSION"),
+    " (+https://github.com/Rocker-Mitchell/aoc-2025)"
+);
+
+/// An error using the Advent of Code site.
+#[derive(Error, Debug)]
+pub enum ClientError {
+    /// No session token was found in the environment or session file.
+    #[error(
+        "no session token found: set {SESSION_ENV} or write the token to {}",
+        .path.display()
+    )]
+    NoSession {
+        /// The path of the session file that was checked.
+        path: PathBuf,
+    },
+
+    /// The session file exists but couldn't be read.
+    #[error("could not read session file at: {}", .path.display())]
+    SessionFile {
+        /// The path of the session file.
+        path: PathBuf,
+        source: io::Error,
+    },
+
+    /// A request failed to complete.
+    #[error("request to {url} failed")]
+    Request {
+        /// The requested URL.
+        url: String,
+        source: ureq::Error,
+    },
+
+    /// A request completed with an unsuccessful status.
+    #[error(
+        "request to {url} returned status {status}{}",
+        status_hint(*.status)
+    )]
+    Status {
+        /// The requested URL.
+        url: String,
+        /// The HTTP status code.
+        status: u16,
+    },
+}
+
+/// Get a hint of the likely cause of an unsuccessful status, for display.
+fn status_hint(status: u16) -> &'static str {
+    match status {
+        400 | 401 | 500 => " (is the session token expired?)",
+        404 => " (is the puzzle unlocked yet?)",
+        _ => "",
+    }
+}
+
+/// A session token for authenticating with the site.
+pub struct Session(String);
+
+impl Session {
+    /// Load a session token from the environment, or else the given session
+    /// file.
+    ///
+    /// Surrounding whitespace is trimmed, and a `session=` cookie prefix is
+    /// accepted.
+    ///
+    /// # Errors
+    ///
+    /// If no token is set in the environment and the file is missing or
+    /// empty, a [`ClientError::NoSession`] error is returned. If the file
+    /// can't be read, a [`ClientError::SessionFile`] error is returned.
+    pub fn load(file: &Path) -> Result<Self, ClientError> {
+        if let Ok(token) = env::var(SESSION_ENV)
+            && let Some(session) = Self::from_token(&token)
+        {
+            return Ok(session);
+        }
+
+        let contents = match fs::read_to_string(file) {
+            Ok(contents) => contents,
+            Err(error) if error.kind() == io::ErrorKind::NotFound => {
+                String::new()
+            }
+            Err(source) => {
+                return Err(ClientError::SessionFile {
+                    path: file.into(),
+                    source,
+                });
+            }
+        };
+        Self::from_token(&contents)
+            .ok_or_else(|| ClientError::NoSession { path: file.into() })
+    }
+
+    /// Create a session from a token, if it isn't blank.
+    fn from_token(token: &str) -> Option<Self> {
+        let token = token.trim();
+        let token = token.strip_prefix("session=").unwrap_or(token);
+        (!token.is_empty()).then(|| Self(token.into()))
+    }
+}
+
+/// A throttle that spaces out requests by a minimum interval.
+struct Throttle {
+    /// The minimum interval between requests.
+    interval: Duration,
+    /// When the last request was made, if any.
+    last: Option<Instant>,
+}
+
+impl Throttle {
+    /// Sleep until the interval has passed since the last request, then mark
+    /// a new request.
+    fn wait(&mut self) {
+        if let Some(last) = self.last {
+            thread::sleep(self.interval.saturating_sub(last.elapsed()));
+        }
+        self.last = Some(Instant::now());
+    }
+}
+
+/// A client for the Advent of Code site.
+pub struct AocClient {
+    /// The HTTP agent making requests.
+    agent: Agent,
+    /// The base URL of the site, without a trailing slash.
+    base_url: String,
+    /// The session authenticating requests.
+    session: Session,
+    /// The throttle spacing out requests.
+    throttle: Throttle,
+}
+
+impl AocClient {
+    /// Construct a client for a site, spacing requests by the given interval.
+    #[must_use]
+    pub fn new(base_url: &str, session: Session, interval: Duration) -> Self {
+        let agent = Agent::config_builder()
+            .http_status_as_error(false)
+            .user_agent(USER_AGENT)
+            .timeout_global(Some(Duration::from_secs(30)))
+            .build()
+            .new_agent();
+        Self {
+            agent,
+            base_url: base_url.trim_end_matches('/').into(),
+            session,
+            throttle: Throttle {
+                interval,
+                last: None,
+            },
+        }
+    }
+
+    /// Get the URL of a day's puzzle page.
+    fn day_url(&self, day: u8) -> String {
+        format!("{}/{YEAR}/day/{day}", self.base_url)
+    }
+
+    /// Download a day's puzzle input.
+    ///
+    /// # Errors
+    ///
+    /// If the request fails or is unsuccessful, an error is returned.
+    pub fn input(&mut self, day: u8) -> Result<String, ClientError> {
+        let url = format!("{}/input", self.day_url(day));
+        self.throttle.wait();
+        let response = self
+            .agent
+            .get(&url)
+            .header("Cookie", &format!("session={}", self.session.0))
+            .call();
+        read_response(url, response)
+    }
+
+    /// Submit an answer to a day's part, returning the response page.
+    ///
+    /// # Errors
+    ///
+    /// If the request fails or is unsuccessful, an error is returned.
+    pub fn submit(
+        &mut self,
+        day: u8,
+        part: SolutionPart,
+        answer: &str,
+    ) -> Result<String, ClientError> {
+        let url = format!("{}/answer", self.day_url(day));
+        let level = match part {
+            SolutionPart::Part1 => "1",
+            SolutionPart::Part2 => "2",
+        };
+        self.throttle.wait();
+        let response = self
+            .agent
+            .post(&url)
+            .header("Cookie", &format!("session={}", self.session.0))
+            .send_form([("level", level), ("answer", answer)]);
+        read_response(url, response)
+    }
+}
+
+/// Read the body of a response, if the request was successful.
+fn read_response(
+    url: String,
+    response: Result<ureq::http::Response<ureq::Body>, ureq::Error>,
+) -> Result<String, ClientError> {
+    let mut response = match response {
+        Ok(response) => response,
+        Err(source) => return Err(ClientError::Request { url, source }),
+    };
+    let status = response.status();
+    if !status.is_success() {
+        return Err(ClientError::Status {
+            url,
+            status: status.as_u16(),
+        });
+    }
+    response
+        .body_mut()
+        .read_to_string()
+        .map_err(|source| ClientError::Request { url, source })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::{BufRead, BufReader, Read, Write};
+    use std::net::TcpListener;
+
+    use super::*;
+
+    /// Serve one request with a canned response on a local port, returning
+    /// the base URL and a handle joining to the request's head and body.
+    fn serve_once(
+        status: u16,
+        body: &'static str,
+    ) -> (String, thread::JoinHandle<String>) {
+        let listener =
+            TcpListener::bind("127.0.0.1:0").expect("failed to bind listener");
+        let address = listener.local_addr().expect("failed to get address");
+        let handle = thread::spawn(move || {
+            let (mut stream, _) =
+                listener.accept().expect("failed to accept connection");
+            let mut reader = BufReader::new(
+                stream.try_clone().expect("failed to clone stream"),
+            );
+            let mut head = String::new();
+            loop {
+                let mut line = String::new();
+                reader.read_line(&mut line).expect("failed to read request");
+                if line.trim().is_empty() {
+                    break;
+                }
+                head.push_str(&line);
+            }
+            // read any form body, as the length given in the head
+            let length = head
+                .lines()
+                .find_map(|line| {
+                    let (name, value) = line.split_once(':')?;
+                    if name.eq_ignore_ascii_case("content-length") {
+                        value.trim().parse().ok()
+                    } else {
+                        None
+                    }
+                })
+                .unwrap_or(0);
+            let mut body_bytes = vec![0; length];
+            reader
+                .read_exact(&mut body_bytes)
+                .expect("failed to read request body");
+            head.push_str(&String::from_utf8_lossy(&body_bytes));
+            write!(
+                stream,
+                "HTTP/1.1 {status} Status\r\nContent-Length: {}\r\n\
+                Connection: close\r\n\r\n{body}",
+                body.len()
+            )
+            .expect("failed to write response");
+            head
+        });
+        (format!("http://{address}/"), handle)
+    }
+
+    #[test]
+    fn session_from_token_trims_and_strips_prefix() {
+        let session = Session::from_token(" session=abc123\n");
+        assert_eq!(session.map(|session| session.0).as_deref(), Some("abc123"));
+        assert!(Session::from_token(" \n").is_none());
+    }
+
+    #[test]
+    fn input_requests_with_session_and_user_agent() {
+        let (base_url, handle) = serve_once(200, "1\n2\n");
+        let mut client =
+            AocClient::new(&base_url, Session("abc".into()), Duration::ZERO);
+        let input = client.input(3).expect("expected input");
+        assert_eq!(input, "1\n2\n");
+
+        let head = handle.join().expect("server panicked").to_lowercase();
+        assert!(head.starts_with("get /2025/day/3/input "), "{head}");
+        assert!(head.contains("cookie: session=abc"), "{head}");
+        assert!(head.contains("user-agent: aoc-2025/"), "{head}");
+    }
+
+    #[test]
+    fn submit_posts_level_and_answer() {
+        let (base_url, handle) = serve_once(
+            200,
+            "<article><p>That's the right answer!</p></article>",
+        );
+        let mut client =
+            AocClient::new(&base_url, Session("abc".into()), Duration::ZERO);
+        let page = client
+            .submit(7, SolutionPart::Part2, "1234")
+            .expect("expected response page");
+        assert!(page.contains("right answer"), "{page}");
+
+        let head = handle.join().expect("server panicked").to_lowercase();
+        assert!(head.starts_with("post /2025/day/7/answer "), "{head}");
+        assert!(head.contains("cookie: session=abc"), "{head}");
+        assert!(head.ends_with("level=2&answer=1234"), "{head}");
+    }
+
+    #[test]
+    fn input_reports_unsuccessful_status() {
+        let (base_url, handle) = serve_once(404, "Not Found");
+        let mut client =
+            AocClient::new(&base_url, Session("abc".into()), Duration::ZERO);
+        let result = client.input(25);
+        handle.join().expect("server panicked");
+        match result {
+            Err(ClientError::Status { status, .. }) => assert_eq!(status, 404),
+            other => panic!("unexpected result: {other:?}"),
+        }
+    }
+}
@@ -0,0 +1,177 @@
+//! Selection of days to run from the CLI.
+
+use std::num::ParseIntError;
+use std::str::FromStr;
+
+use thiserror::Error;
+
+/// A selection of days, in the order given and without duplicates.
+///
+/// Parses from a single day (`3`), an inclusive range (`1..=11`), an exclusive
+/// range (`1..12`), or a comma separated list of any of those (`1,3,5..=7`).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DaySelection(Vec<u8>);
+
+impl DaySelection {
+    /// Get the selected days.
+    #[must_use]
+    pub fn days(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl FromIterator<u8> for DaySelection {
+    fn from_iter<I: IntoIterator<Item = u8>>(iter: I) -> Self {
+        let mut days = Vec::new();
+        for day in iter {
+            if !days.contains(&day) {
+                days.push(day);
+            }
+        }
+        Self(days)
+    }
+}
+
+/// An error parsing a [`DaySelection`].
+#[derive(Error, Debug)]
+pub enum DaySelectionError {
+    /// The selection had no days.
+    #[error("no days selected")]
+    Empty,
+
+    /// A day failed to parse as a number.
+    #[error("invalid day {string:?}")]
+    InvalidDay {
+        /// The string that failed to parse.
+        string: String,
+        source: ParseIntError,
+    },
+
+    /// A range ended before it started.
+    #[error("range {string:?} has no days")]
+    EmptyRange {
+        /// The range string.
+        string: String,
+    },
+}
+
+/// Parse a day number from a string.
+fn parse_day(string: &str) -> Result<u8, DaySelectionError> {
+    let trimmed = string.trim();
+    trimmed
+        .parse()
+        .map_err(|source| DaySelectionError::InvalidDay {
+            string: trimmed.into(),
+            source,
+        })
+}
+
+/// Parse a single day or range of days into a list of days.
+fn parse_item(item: &str) -> Result<Vec<u8>, DaySelectionError> {
+    // check for the inclusive syntax first, as it also contains ".."
+    let range = if let Some((start, end)) = item.split_once("..=") {
+        parse_day(start)?..=parse_day(end)?
+    } else if let Some((start, end)) = item.split_once("..") {
+        let end = parse_day(end)?;
+        if end == 0 {
+            return Err(DaySelectionError::EmptyRange {
+                string: item.trim().into(),
+            });
+        }
+        parse_day(start)?..=end - 1
+    } else {
+        let day = parse_day(item)?;
+        day..=day
+    };
+
+    if range.is_empty() {
+        Err(DaySelectionError::EmptyRange {
+            string: item.trim().into(),
+        })
+    } else {
+        Ok(range.collect())
+    }
+}
+
+impl FromStr for DaySelection {
+    type Err = DaySelectionError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut days = Vec::new();
+        for item in s.split(',').filter(|item| !item.trim().is_empty()) {
+            days.extend(parse_item(item)?);
+        }
+
+        if days.is_empty() {
+            Err(DaySelectionError::Empty)
+        } else {
+            Ok(days.into_iter().collect())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_single_day() {
+        let selection: DaySelection = "7".parse().expect("expected to parse");
+        assert_eq!(selection.days(), &[7]);
+    }
+
+    #[test]
+    fn parses_inclusive_range() {
+        let selection: DaySelection =
+            "1..=4".parse().expect("expected to parse");
+        assert_eq!(selection.days(), &[1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn parses_exclusive_range() {
+        let selection: DaySelection =
+            "1..4".parse().expect("expected to parse");
+        assert_eq!(selection.days(), &[1, 2, 3]);
+    }
+
+    #[test]
+    fn parses_list_without_duplicates() {
+        let selection: DaySelection =
+            "3, 1,5..=7,6".parse().expect("expected to parse");
+        assert_eq!(selection.days(), &[3, 1, 5, 6, 7]);
+    }
+
+    #[test]
+    fn errors_on_invalid_day() {
+        let result = "1,x".parse::<DaySelection>();
+        match result {
+            Err(DaySelectionError::InvalidDay { string, .. }) => {
+                assert_eq!(string, "x");
+            }
+            other => panic!("unexpected result: {other:?}"),
+        }
+    }
+
+    #[test]
+    fn errors_on_empty_range() {
+        let result = "5..=2".parse::<DaySelection>();
+        assert!(
+            matches!(result, Err(DaySelectionError::EmptyRange { .. })),
+            "unexpected result: {result:?}"
+        );
+        let result = "0..0".parse::<DaySelection>();
+        assert!(
+            matches!(result, Err(DaySelectionError::EmptyRange { .. })),
+            "unexpected result: {result:?}"
+        );
+    }
+
+    #[test]
+    fn errors_on_empty_selection() {
+        let result = " , ".parse::<DaySelection>();
+        assert!(
+            matches!(result, Err(DaySelectionError::Empty)),
+            "unexpected result: {result:?}"
+        );
+    }
+}
@@ -0,0 +1,108 @@
+//! Rendering of parse errors as source diagnostics.
+
+use std::error::Error;
+use std::fmt::Write;
+
+use aoc_framework::ParseError;
+
+/// Render the input line that caused a parse error, with carets under the
+/// offending characters, in the style of compiler diagnostics.
+///
+/// The first [`ParseError`] in the error's chain with a known location is
+/// rendered, labelled with the innermost cause. If there is no such error or
+/// the location is outside the input, `None` is returned.
+#[must_use]
+pub fn render_diagnostic(input: &str, error: &anyhow::Error) -> Option<String> {
+    let (parse_error, span) = error.chain().find_map(|cause| {
+        let parse_error = cause.downcast_ref::<ParseError>()?;
+        Some((parse_error, parse_error.location()?))
+    })?;
+    let text = input.lines().nth(span.line.checked_sub(1)?)?;
+
+    // label with the root cause, as outer errors only give the location
+    let mut cause: &dyn Error = parse_error;
+    while let Some(source) = cause.source() {
+        cause = source;
+    }
+
+    // underline the whole line if the column is unknown
+    let (start, width) =
+        span.column.map_or((0, text.chars().count()), |column| {
+            (column.saturating_sub(1), span.width)
+        });
+
+    let number = span.line.to_string();
+    let gutter = " ".repeat(number.len());
+    let mut rendered = String::new();
+    // writing to a string can't fail
+    match span.column {
+        Some(column) => {
+            let _ = writeln!(
+                rendered,
+                "{gutter}--> line {number}, column {column}"
+            );
+        }
+        None => {
+            let _ = writeln!(rendered, "{gutter}--> line {number}");
+        }
+    }
+    let _ = writeln!(rendered, "{gutter} |");
+    let _ = writeln!(rendered, "{number} | {text}");
+    let _ = writeln!(
+        rendered,
+        "{gutter} | {}{} {cause}",
+        " ".repeat(start),
+        "^".repeat(width.max(1)),
+    );
+    Some(rendered)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn renders_caret_under_column() {
+        let error =
+            anyhow::Error::from(ParseError::invalid_line_from_zero_index(
+                9,
+                ParseError::invalid_column_from_zero_index(
+                    2,
+                    1,
+                    ParseError::ParseChar('x'),
+                ),
+            ));
+        let input = "1\n2\n3\n4\n5\n6\n7\n8\n9\n12x4\n";
+        let expected = "  --> line 10, column 3
+   |
+10 | 12x4
+   |   ^ invalid character: 'x'
+";
+        assert_eq!(render_diagnostic(input, &error).as_deref(), Some(expected));
+    }
+
+    #[test]
+    fn renders_whole_line_without_column() {
+        let error = anyhow::Error::from(
+            ParseError::invalid_line_from_zero_index(0, ParseError::EmptyLine),
+        )
+        .context("failed to run solution");
+        let expected = " --> line 1
+  |
+1 | abc
+  | ^^^ line was empty
+";
+        assert_eq!(render_diagnostic("abc", &error).as_deref(), Some(expected));
+    }
+
+    #[test]
+    fn skips_errors_without_location() {
+        let error = anyhow::Error::from(ParseError::EmptyInput);
+        assert_eq!(render_diagnostic("", &error), None);
+
+        let error = anyhow::Error::from(
+            ParseError::invalid_line_from_zero_index(5, ParseError::EmptyLine),
+        );
+        assert_eq!(render_diagnostic("abc", &error), None);
+    }
+}
@@ -20,33 +20,91 @@
 )]
 #![deny(clippy::expect_used, clippy::unwrap_used)]
 
-use std::fmt::Display;
-use std::fs;
-use std::path::PathBuf;
-use std::time::Duration;
+use std::collections::BTreeMap;
+use std::io::{self, IsTerminal};
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime};
+use std::{env, fs, process, slice, thread};
 
-use anyhow::{Context, Result};
-use aoc_framework::{OutputHandler, SolutionPart};
-use clap::{ArgAction, Parser};
-use solutions::run_day;
+use anyhow::{Context, Result, bail};
+use aoc_framework::bench::BenchOptions;
+use aoc_framework::{OutputHandler, PartSelection, SolutionPart};
+use clap::{ArgAction, Args, Parser, Subcommand};
+use solutions::{
+    DaySolutionError, SOLUTIONS, get_solution, puzzle_days, run_day,
+};
 
 // TODO possible packages to add later:
 // - anstyle and anstream for styling clap and prints
 
+mod bench;
+mod check;
+mod client;
+mod days;
+mod diagnostic;
 mod format;
+mod output;
+mod scaffold;
+mod submit;
+mod summary;
+mod watch;
 
-use format::format_duration;
+use check::{CheckingHandler, ExpectedAnswers, PartCheck, count_failed};
+use client::{AocClient, DEFAULT_BASE_URL, Session};
+use days::DaySelection;
+use diagnostic::render_diagnostic;
+use output::{CliHandler, CliOutputHandler, JsonOutputHandler, OutputFormat};
+use scaffold::{ScaffoldOptions, scaffold};
+use submit::{GuessLog, Verdict};
+use summary::{DayRecord, DayStatus, RecordingHandler};
+use watch::{Snapshot, diff_answers, example_inputs};
 
 /// Advent of Code 2025 challenge solver.
 #[derive(Parser, Debug)]
+#[command(
+    args_conflicts_with_subcommands = true,
+    subcommand_negates_reqs = true
+)]
+#[expect(
+    clippy::struct_excessive_bools,
+    reason = "command line flags are independent switches"
+)]
 struct Cli {
-    /// The day's solution to run (e.g. 1, 2, etc.).
-    day: u8,
+    /// A command to run instead of running solutions.
+    #[command(subcommand)]
+    command: Option<Command>,
+
+    /// The days' solutions to run: a day (e.g. 1), a range (e.g. 1..=11), or
+    /// a comma separated list (e.g. 1,3,7).
+    #[arg(
+        value_name = "DAYS",
+        required_unless_present_any = ["all", "list"],
+        conflicts_with_all = ["all", "list"]
+    )]
+    days: Option<DaySelection>,
+
+    /// Run every implemented day.
+    #[arg(short, long, action = ArgAction::SetTrue)]
+    all: bool,
+
+    /// List the days with implemented solutions.
+    #[arg(short, long, action = ArgAction::SetTrue, conflicts_with = "all")]
+    list: bool,
 
-    /// Sets an alternative input file to use over default input.
-    #[arg(short, long, value_name = "FILE")]
+    /// Sets an alternative input file to use over default input, or `-` to
+    /// read standard input. Only valid when running a single day.
+    #[arg(short, long, value_name = "FILE", conflicts_with = "all")]
     input: Option<PathBuf>,
 
+    /// Run only the given part (1 or 2) of the solutions.
+    #[arg(
+        short,
+        long,
+        value_name = "PART",
+        value_parser = clap::value_parser!(u8).range(1..=2)
+    )]
+    part: Option<u8>,
+
     /// Measure the time of parsing and running parts.
     #[arg(short, long, action = ArgAction::SetTrue)]
     timed: bool,
@@ -55,18 +113,225 @@ struct Cli {
     /// 0 = always print.
     #[arg(long, value_name = "NUMBER", default_value_t)]
     min_timing_ms: u64,
+
+    /// Check answers against expected answers, failing on any mismatch.
+    /// Checking an alternative input file needs an alternative answers file.
+    #[arg(short, long, action = ArgAction::SetTrue)]
+    check: bool,
+
+    /// Sets an alternative answers file to check against over the default
+    /// answers file.
+    #[arg(long, value_name = "FILE", requires = "check")]
+    answers: Option<PathBuf>,
+
+    /// Benchmark solutions over the given number of runs, instead of printing
+    /// answers.
+    #[arg(
+        long,
+        value_name = "RUNS",
+        value_parser = clap::value_parser!(u32).range(1..),
+        conflicts_with = "check"
+    )]
+    bench: Option<u32>,
+
+    /// The number of warm-up runs before benchmarking.
+    #[arg(long, value_name = "RUNS", default_value_t = 3, requires = "bench")]
+    warmup: u32,
+
+    /// The format to print output in.
+    #[arg(short, long, value_enum, default_value_t)]
+    output: OutputFormat,
+
+    /// Rerun a single day whenever its input or example files change,
+    /// showing how answers changed from the previous run. When the day's
+    /// source file changes, the CLI is rebuilt with Cargo and restarted.
+    #[arg(
+        short,
+        long,
+        action = ArgAction::SetTrue,
+        conflicts_with_all = ["all", "check", "bench", "output"]
+    )]
+    watch: bool,
+
+    /// The interval (in milliseconds) to poll watched files for changes.
+    #[arg(
+        long,
+        value_name = "NUMBER",
+        default_value_t = 500,
+        requires = "watch"
+    )]
+    poll_ms: u64,
 }
 
-/// Read the default input file for the day to a string.
-fn get_default_input(day: u8) -> Result<String> {
+/// Commands besides running solutions.
+#[derive(Subcommand, Debug)]
+enum Command {
+    /// Download puzzle inputs from the Advent of Code site into the inputs
+    /// directory.
+    Fetch(FetchArgs),
+
+    /// Submit the answer of a day's part to the Advent of Code site.
+    Submit(SubmitArgs),
+
+    /// Generate a new day's solution module and example test skeleton in the
+    /// solutions crate.
+    New(NewArgs),
+}
+
+/// Arguments for fetching puzzle inputs.
+#[derive(Args, Debug)]
+struct FetchArgs {
+    /// The days to fetch inputs of: a day (e.g. 1), a range (e.g. 1..=11), or
+    /// a comma separated list (e.g. 1,3,7).
+    #[arg(
+        value_name = "DAYS",
+        required_unless_present = "all",
+        conflicts_with = "all"
+    )]
+    days: Option<DaySelection>,
+
+    /// Fetch inputs of every implemented day.
+    #[arg(short, long, action = ArgAction::SetTrue)]
+    all: bool,
+
+    /// Download inputs again, replacing existing input files.
+    #[arg(short, long, action = ArgAction::SetTrue)]
+    force: bool,
+
+    #[command(flatten)]
+    site: SiteArgs,
+}
+
+/// Arguments for submitting an answer.
+#[derive(Args, Debug)]
+struct SubmitArgs {
+    /// The day of the puzzle to submit an answer to.
+    #[arg(value_name = "DAY")]
+    day: u8,
+
+    /// The part (1 or 2) of the puzzle to submit an answer to.
+    #[arg(
+        value_name = "PART",
+        value_parser = clap::value_parser!(u8).range(1..=2)
+    )]
+    part: u8,
+
+    /// Sets an alternative input file to compute the answer from over the
+    /// default input, or `-` to read standard input.
+    #[arg(short, long, value_name = "FILE", conflicts_with = "answer")]
+    input: Option<PathBuf>,
+
+    /// Submit the given answer instead of running the solution to compute it.
+    /// Without it, the part is run again rather than reusing an earlier run.
+    #[arg(long, value_name = "ANSWER")]
+    answer: Option<String>,
+
+    #[command(flatten)]
+    site: SiteArgs,
+}
+
+/// Arguments for generating a new day's solution.
+#[derive(Args, Debug)]
+struct NewArgs {
+    /// The day of the puzzle to generate a solution for.
+    #[arg(
+        value_name = "DAY",
+        value_parser = clap::value_parser!(u8).range(1..=25)
+    )]
+    day: u8,
+
+    /// The title of the puzzle, used in the solution's name.
+    #[arg(short, long, value_name = "TITLE")]
+    name: Option<String>,
+
+    /// Implement parts with a distinct parsing step, instead of parsing
+    /// within each part.
+    #[arg(long, action = ArgAction::SetTrue)]
+    parsed: bool,
+}
+
+/// Arguments for connecting to the Advent of Code site.
+#[derive(Args, Debug)]
+struct SiteArgs {
+    /// The base URL of the Advent of Code site.
+    #[arg(
+        long,
+        value_name = "URL",
+        env = "AOC_BASE_URL",
+        default_value = DEFAULT_BASE_URL
+    )]
+    base_url: String,
+
+    /// Sets an alternative file to read a session token from over the default
+    /// session file, when the session environment variable is unset.
+    #[arg(long, value_name = "FILE")]
+    session_file: Option<PathBuf>,
+
+    /// Minimum duration (in milliseconds) between requests to the site.
+    #[arg(long, value_name = "NUMBER", default_value_t = 3000)]
+    throttle_ms: u64,
+}
+
+impl SiteArgs {
+    /// Construct a client for the site, loading a session token.
+    fn client(self) -> Result<AocClient> {
+        // define file path relative to current directory
+        let path = self
+            .session_file
+            .unwrap_or_else(|| PathBuf::from("inputs").join(".session"));
+        let session = Session::load(&path)?;
+        Ok(AocClient::new(
+            &self.base_url,
+            session,
+            Duration::from_millis(self.throttle_ms),
+        ))
+    }
+}
+
+/// Get the solution part of a part number, validated by clap to be 1 or 2.
+fn part_from_number(number: u8) -> SolutionPart {
+    if number == 1 {
+        SolutionPart::Part1
+    } else {
+        SolutionPart::Part2
+    }
+}
+
+/// Get the path of the default input file for the day.
+fn default_input_path(day: u8) -> PathBuf {
     let filename = format!("day{day:02}.txt");
     // define file path relative to current directory
-    let path = PathBuf::from("inputs").join(filename);
+    PathBuf::from("inputs").join(filename)
+}
+
+/// The input file argument that reads input from standard input.
+const STDIN_INPUT: &str = "-";
+
+/// Read puzzle input from standard input to a string.
+fn read_stdin_input() -> Result<String> {
+    io::read_to_string(io::stdin()).context("could not read input from stdin")
+}
+
+/// Read an input file to a string, reading standard input if the path is
+/// `-`.
+fn read_input_file(path: &Path) -> Result<String> {
+    if path == Path::new(STDIN_INPUT) {
+        return read_stdin_input();
+    }
+    fs::read_to_string(path).with_context(|| {
+        format!("could not read input file at: {}", path.display())
+    })
+}
+
+/// Read the default input file for the day to a string.
+fn get_default_input(day: u8) -> Result<String> {
+    let path = default_input_path(day);
 
     fs::read_to_string(&path).with_context(|| {
         format!(
             "default input file missing: {}\n\n\
-            please create the file or provide the input file argument",
+            please create the file, download it with the fetch command, \
+            provide the input file argument, or pipe input to stdin",
             path.display()
         )
     })
@@ -74,85 +339,724 @@ fn get_default_input(day: u8) -> Result<String> {
 
 /// Try to read the given input file to a string, otherwise get the default
 /// input for the day.
+///
+/// If the default input file is missing and input is piped to stdin, the
+/// piped input is read instead.
 fn get_input(day: u8, input_file: Option<PathBuf>) -> Result<String> {
-    input_file.map_or_else(
-        || get_default_input(day),
-        |path| {
-            fs::read_to_string(&path).with_context(|| {
-                format!("could not read input file at: {}", path.display())
-            })
-        },
-    )
+    if let Some(path) = input_file {
+        return read_input_file(&path);
+    }
+    if !default_input_path(day).exists() && !io::stdin().is_terminal() {
+        let input = read_stdin_input()?;
+        // nothing piped, like stdin of /dev/null, falls back to the file
+        if !input.is_empty() {
+            return Ok(input);
+        }
+    }
+    get_default_input(day)
+}
+
+/// Read and parse the expected answers file, or the default answers file if
+/// no path is given.
+fn load_answers(answers_file: Option<PathBuf>) -> Result<ExpectedAnswers> {
+    // define file path relative to current directory
+    let path = answers_file
+        .unwrap_or_else(|| PathBuf::from("inputs").join("answers.toml"));
+    let text = fs::read_to_string(&path).with_context(|| {
+        format!("could not read answers file at: {}", path.display())
+    })?;
+    text.parse().with_context(|| {
+        format!("could not parse answers file at: {}", path.display())
+    })
 }
 
-/// The output event handler for the Advent of Code CLI.
+/// Options for running days' solutions.
+#[derive(Debug, Clone, Copy)]
+struct RunOptions<'a> {
+    /// The parts to run.
+    parts: PartSelection,
+    /// Whether to output timing of parsing and running parts.
+    timed: bool,
+    /// The expected answers to check against, if checking.
+    answers: Option<&'a ExpectedAnswers>,
+}
+
+/// Run a day, checking its answers if expected answers are given.
 ///
-/// This tracks a minimum timing threshold to control printing timing
-/// information. Any durations under the minimum will be omitted.
-pub struct CliOutputHandler {
-    /// The minimum timing threshold.
-    min_timing: Duration,
+/// Checks are returned alongside the result of running the day, covering
+/// the parts output before any failure.
+fn run_day_checked(
+    day: u8,
+    handler: &mut dyn OutputHandler,
+    input: &str,
+    options: RunOptions,
+) -> (Result<(), DaySolutionError>, Option<Vec<PartCheck>>) {
+    let RunOptions {
+        parts,
+        timed,
+        answers,
+    } = options;
+    match answers {
+        Some(answers) => {
+            let mut checker = CheckingHandler::new(handler, answers.day(day));
+            let result = run_day(day, &mut checker, input, parts, timed);
+            (result, Some(checker.finish()))
+        }
+        None => (run_day(day, handler, input, parts, timed), None),
+    }
+}
+
+/// Print a diagnostic of the input that caused a failure, if the failure was
+/// a parse error with a known location.
+///
+/// The diagnostic is printed to standard error, to keep it apart from
+/// structured output.
+fn print_diagnostic(input: &str, error: &anyhow::Error) {
+    if let Some(diagnostic) = render_diagnostic(input, error) {
+        eprint!("{diagnostic}");
+    }
+}
+
+/// Check a day in a batch can run and read its default input.
+///
+/// If the day can't run, the handler is told why the day is skipped and a
+/// short reason for the summary is returned.
+fn prepare_batch_day(
+    day: u8,
+    handler: &mut dyn CliHandler,
+) -> Result<String, &'static str> {
+    if get_solution(day).is_err() {
+        handler.day_skipped(day, "solution not yet implemented");
+        return Err("not implemented");
+    }
+
+    let path = default_input_path(day);
+    if !path.exists() {
+        handler.day_skipped(
+            day,
+            &format!("missing input file {}", path.display()),
+        );
+        return Err("missing input");
+    }
+
+    get_default_input(day).map_err(|error| {
+        handler.day_skipped(day, &format!("{error:#}"));
+        "unreadable input"
+    })
+}
+
+/// Benchmark a day's solution, outputting its name and report.
+fn bench_day(
+    day: u8,
+    handler: &mut dyn CliHandler,
+    input: &str,
+    options: BenchOptions,
+) -> Result<(), DaySolutionError> {
+    let entry = get_solution(day)?;
+    handler.solution_name(entry.name);
+    let report = entry.bench(input, &options)?;
+    handler.day_bench(day, &report);
+    Ok(())
+}
+
+/// Benchmark a batch of days.
+///
+/// Days are skipped and reported like [`run_batch`].
+///
+/// # Errors
+///
+/// If any day failed, an error is returned after all days have run.
+fn bench_batch(
+    days: &[u8],
+    handler: &mut dyn CliHandler,
+    options: BenchOptions,
+) -> Result<()> {
+    let mut failed = 0;
+    for &day in days {
+        let Ok(input_text) = prepare_batch_day(day, handler) else {
+            continue;
+        };
+
+        handler.day_start(day);
+        if let Err(error) = bench_day(day, handler, &input_text, options) {
+            let error = anyhow::Error::from(error);
+            handler.day_failed(day, &error);
+            print_diagnostic(&input_text, &error);
+            failed += 1;
+        }
+    }
+
+    handler.finish()?;
+    if failed > 0 {
+        bail!("{failed} day(s) failed to run");
+    }
+    Ok(())
+}
+
+/// Benchmark a single day.
+///
+/// A failure is output like [`bench_batch`], finishing output before the
+/// error is returned.
+///
+/// # Errors
+///
+/// If the day failed, an error is returned.
+fn bench_single(
+    day: u8,
+    handler: &mut dyn CliHandler,
+    input: &str,
+    options: BenchOptions,
+) -> Result<()> {
+    handler.day_start(day);
+    let result = bench_day(day, handler, input, options);
+    if let Err(error) = result {
+        let error = anyhow::Error::from(error);
+        handler.day_failed(day, &error);
+        print_diagnostic(input, &error);
+        handler.finish()?;
+        bail!("day {day} failed to benchmark");
+    }
+    handler.finish()
+}
+
+/// Run a single day, checking its answers if expected answers are given.
+///
+/// A failure is output like [`run_batch`], finishing output before the error
+/// is returned.
+///
+/// # Errors
+///
+/// If the day failed or any checked answer did not match, an error is
+/// returned.
+fn run_single(
+    day: u8,
+    handler: &mut dyn CliHandler,
+    input: &str,
+    options: RunOptions,
+) -> Result<()> {
+    handler.day_start(day);
+    let (result, checks) = run_day_checked(day, handler, input, options);
+    let result = result.map_err(|error| {
+        let error = anyhow::Error::from(error);
+        handler.day_failed(day, &error);
+        print_diagnostic(input, &error);
+    });
+    let mismatches = checks.map_or(0, |checks| {
+        handler.day_checked(day, &checks);
+        count_failed(&checks)
+    });
+    handler.finish()?;
+
+    if result.is_err() {
+        bail!("day {day} failed to run");
+    }
+    if mismatches > 0 {
+        bail!("{mismatches} answer(s) did not match expected answers");
+    }
+    Ok(())
+}
+
+/// Run a batch of days, printing a summary table at the end.
+///
+/// Days missing their default input file are skipped, and days that fail are
+/// reported without stopping the rest of the batch.
+///
+/// # Errors
+///
+/// If any day failed or any checked answer did not match, an error is
+/// returned after all days have run.
+fn run_batch(
+    days: &[u8],
+    handler: &mut dyn CliHandler,
+    options: RunOptions,
+) -> Result<()> {
+    let mut records = Vec::with_capacity(days.len());
+    let mut mismatches = 0;
+
+    for &day in days {
+        let input_text = match prepare_batch_day(day, handler) {
+            Ok(input_text) => input_text,
+            Err(reason) => {
+                records.push(DayRecord::skipped(day, reason));
+                continue;
+            }
+        };
+
+        handler.day_start(day);
+        // always time runs so the summary can total durations
+        let mut recorder = RecordingHandler::new(day, handler, options.timed);
+        let (result, checks) = run_day_checked(
+            day,
+            &mut recorder,
+            &input_text,
+            RunOptions {
+                timed: true,
+                ..options
+            },
+        );
+        let mut record = recorder.finish();
+        if let Err(error) = result {
+            let error = anyhow::Error::from(error);
+            handler.day_failed(day, &error);
+            print_diagnostic(&input_text, &error);
+            record.status = DayStatus::Failed(error.to_string());
+        }
+        if let Some(checks) = checks {
+            handler.day_checked(day, &checks);
+            mismatches += count_failed(&checks);
+        }
+        records.push(record);
+    }
+
+    handler.summary(&records);
+    handler.finish()?;
+
+    let failed = records
+        .iter()
+        .filter(|record| matches!(record.status, DayStatus::Failed(_)))
+        .count();
+    if failed > 0 {
+        bail!("{failed} day(s) failed to run");
+    }
+    if mismatches > 0 {
+        bail!("{mismatches} answer(s) did not match expected answers");
+    }
+    Ok(())
+}
+
+/// Download the inputs of days into their default input files.
+///
+/// Days with an existing input file are skipped unless forced, so inputs are
+/// only downloaded once.
+fn fetch_inputs(args: FetchArgs) -> Result<()> {
+    let days: Vec<u8> = if args.all {
+        puzzle_days().collect()
+    } else {
+        args.days
+            .map(|selection| selection.days().to_vec())
+            .unwrap_or_default()
+    };
+    if let Some(day) = days.iter().find(|day| !(1..=25).contains(*day)) {
+        bail!("day {day} has no puzzle to fetch");
+    }
+
+    let mut client = args.site.client()?;
+    for day in days {
+        let path = default_input_path(day);
+        if path.exists() && !args.force {
+            println!("Day {day}: input exists at {}", path.display());
+            continue;
+        }
+
+        let input = client
+            .input(day)
+            .with_context(|| format!("failed to fetch day {day} input"))?;
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent).with_context(|| {
+                format!("could not create directory: {}", parent.display())
+            })?;
+        }
+        fs::write(&path, input).with_context(|| {
+            format!("could not write input file at: {}", path.display())
+        })?;
+        println!("Day {day}: fetched input to {}", path.display());
+    }
+    Ok(())
 }
 
-impl CliOutputHandler {
-    /// Construct an instance with the given minimum timing threshold.
-    #[must_use]
-    pub fn new(min_timing: Duration) -> Self {
-        Self { min_timing }
+/// Run a day's part to compute its answer, printing the run.
+fn compute_answer(
+    day: u8,
+    part: SolutionPart,
+    input_file: Option<PathBuf>,
+) -> Result<String> {
+    get_solution(day)?;
+    let input_text = get_input(day, input_file)?;
+
+    let mut handler = CliOutputHandler::new(Duration::ZERO);
+    let mut recorder = RecordingHandler::new(day, &mut handler, false);
+    let result = run_day(
+        day,
+        &mut recorder,
+        &input_text,
+        PartSelection::Only(part),
+        false,
+    );
+    let record = recorder.finish();
+    if let Err(error) = result {
+        let error = anyhow::Error::from(error);
+        print_diagnostic(&input_text, &error);
+        return Err(error.context("failed to run solution"));
     }
 
-    /// Check if the given duration is above the minimum timing.
-    fn duration_over_min(&self, duration: Duration) -> bool {
-        duration >= self.min_timing
+    record
+        .answer(part)
+        .map(String::from)
+        .with_context(|| format!("{} output no answer", part.default_name()))
+}
+
+/// Read the wrong guesses file, or an empty log if the file is missing.
+fn load_guesses(path: &Path) -> Result<GuessLog> {
+    if !path.exists() {
+        return Ok(GuessLog::default());
     }
+    let text = fs::read_to_string(path).with_context(|| {
+        format!("could not read guesses file at: {}", path.display())
+    })?;
+    text.parse().with_context(|| {
+        format!("could not parse guesses file at: {}", path.display())
+    })
 }
 
-impl OutputHandler for CliOutputHandler {
-    fn solution_name(&mut self, name: &str) {
-        println!("= {name} =");
+/// Write the wrong guesses file.
+fn write_guesses(path: &Path, guesses: &GuessLog) -> Result<()> {
+    fs::write(path, guesses.to_string()).with_context(|| {
+        format!("could not write guesses file at: {}", path.display())
+    })
+}
+
+/// Submit the answer of a day's part, computing it if not given.
+///
+/// Wrong answers and the time the site asks to wait are recorded in a
+/// guesses file. Answers known to be wrong from it, or submitted before the
+/// wait is over, are refused before submitting.
+///
+/// # Errors
+///
+/// If the answer is known to be wrong or isn't accepted as correct, an error
+/// is returned.
+fn submit_answer(args: SubmitArgs) -> Result<()> {
+    let SubmitArgs {
+        day,
+        part,
+        input,
+        answer,
+        site,
+    } = args;
+    let part = part_from_number(part);
+    let answer = match answer {
+        Some(answer) => answer,
+        None => compute_answer(day, part, input)?,
+    };
+    let answer = answer.trim();
+    if answer.is_empty() {
+        bail!("refusing to submit an empty answer");
+    }
+
+    // define file path relative to current directory
+    let path = PathBuf::from("inputs").join("guesses.toml");
+    let mut guesses = load_guesses(&path)?;
+    guesses
+        .check(day, part, answer)
+        .with_context(|| "refusing to submit a known wrong answer")?;
+    guesses
+        .check_wait(day, SystemTime::now())
+        .with_context(|| "refusing to submit before the site allows it")?;
+
+    let mut client = site.client()?;
+    let page = client.submit(day, part, answer).with_context(|| {
+        format!("failed to submit day {day} {}", part.default_name())
+    })?;
+    let verdict = Verdict::from_response(&page);
+    println!(
+        "Day {day} {}: submitted {answer}: {verdict}",
+        part.default_name()
+    );
+
+    match verdict {
+        Verdict::Correct => Ok(()),
+        Verdict::Wrong(kind) => {
+            guesses.record(day, part, answer, kind);
+            write_guesses(&path, &guesses)?;
+            bail!("answer was not accepted");
+        }
+        Verdict::Wait(Some(left)) => {
+            guesses.record_wait(day, SystemTime::now() + left);
+            write_guesses(&path, &guesses)?;
+            bail!("answer was not accepted");
+        }
+        _ => bail!("answer was not accepted"),
     }
+}
 
-    fn parse_start(&mut self) {
-        // do nothing
+/// Generate a new day's solution in the solutions crate, printing the files
+/// created or updated.
+fn new_solution(args: NewArgs) -> Result<()> {
+    // define crate path relative to current directory
+    let crate_dir = PathBuf::from("solutions");
+    let options = ScaffoldOptions {
+        day: args.day,
+        title: args.name,
+        parsed: args.parsed,
+    };
+    let paths = scaffold(&crate_dir, &options).with_context(|| {
+        format!("failed to generate day {} solution", args.day)
+    })?;
+    for path in paths {
+        println!("wrote {}", path.display());
     }
+    Ok(())
+}
+
+/// Run a day on an input file, printing how its answers changed from the
+/// previous run of the file.
+fn watch_run(
+    day: u8,
+    path: &Path,
+    options: RunOptions,
+    min_timing: Duration,
+    previous: &mut BTreeMap<PathBuf, DayRecord>,
+) {
+    println!("==> {} <==", path.display());
+    let input_text = match read_input_file(path) {
+        Ok(input_text) => input_text,
+        Err(error) => {
+            println!("{error:#}");
+            previous.remove(path);
+            return;
+        }
+    };
 
-    fn parse_end(&mut self) {
-        // do nothing
+    let mut handler = CliOutputHandler::new(min_timing);
+    let mut recorder = RecordingHandler::new(day, &mut handler, true);
+    let result = run_day(
+        day,
+        &mut recorder,
+        &input_text,
+        options.parts,
+        options.timed,
+    );
+    let record = recorder.finish();
+    if let Err(error) = result {
+        let error = anyhow::Error::from(error);
+        println!("Day {day} failed: {error:#}");
+        print_diagnostic(&input_text, &error);
     }
 
-    fn parse_end_timed(&mut self, duration: Duration) {
-        if self.duration_over_min(duration) {
-            println!("Input parsed in {}", format_duration(duration));
+    if let Some(earlier) = previous.get(path) {
+        println!("-- Changes --");
+        for line in diff_answers(earlier, &record) {
+            println!("{line}");
         }
     }
+    println!();
+    previous.insert(path.into(), record);
+}
 
-    fn part_start(&mut self, part: SolutionPart) {
-        println!("-- {} --", part.default_name());
+/// Rebuild the CLI with Cargo in the profile of the running binary.
+///
+/// # Errors
+///
+/// If Cargo can't be run or the build fails, an error is returned.
+fn rebuild() -> Result<()> {
+    // Cargo sets the path to itself for binaries it runs
+    let cargo = env::var_os("CARGO").unwrap_or_else(|| "cargo".into());
+    let mut command = process::Command::new(cargo);
+    command.args(["build", "--bin", env!("CARGO_BIN_NAME")]);
+    if !cfg!(debug_assertions) {
+        command.arg("--release");
     }
+    let status = command.status().context("could not run cargo")?;
+    if !status.success() {
+        bail!("rebuild failed ({status})");
+    }
+    Ok(())
+}
+
+/// Replace the running process with the rebuilt CLI binary, run with the
+/// same arguments.
+///
+/// The process is replaced rather than spawning a child, so restarting on
+/// every change doesn't stack processes.
+///
+/// # Errors
+///
+/// If the binary can't be run, an error is returned. On success, this
+/// doesn't return.
+#[cfg(unix)]
+fn restart(binary: &Path) -> Result<()> {
+    use std::os::unix::process::CommandExt;
+
+    let error = process::Command::new(binary)
+        .args(env::args_os().skip(1))
+        .exec();
+    Err(error).with_context(|| format!("could not run {}", binary.display()))
+}
+
+/// Stop watching after a rebuild, as the running process can't be replaced
+/// with the rebuilt binary on this platform.
+///
+/// # Errors
+///
+/// An error is always returned, asking to restart the watch.
+#[cfg(not(unix))]
+fn restart(binary: &Path) -> Result<()> {
+    bail!(
+        "rebuilt {}; restart the watch to run the new solution",
+        binary.display()
+    )
+}
 
-    fn part_output(&mut self, _part: SolutionPart, output: &dyn Display) {
-        println!("{output}");
+/// Watch a day's input and example files, rerunning the day on each file
+/// that changes.
+///
+/// Files are polled for changes in modification time. When the day's source
+/// file changes, the CLI is rebuilt with Cargo and the process is replaced
+/// with the rebuilt binary to run the new solution. If the build fails, the
+/// old solution keeps watching.
+fn watch_day(
+    day: u8,
+    input_file: Option<PathBuf>,
+    options: RunOptions,
+    min_timing: Duration,
+    interval: Duration,
+) -> Result<()> {
+    get_solution(day)?;
+    let input_path = input_file.unwrap_or_else(|| default_input_path(day));
+    if input_path == Path::new(STDIN_INPUT) {
+        bail!("watch mode can't watch input from stdin");
     }
+    // the path is found before rebuilding, as replacing the binary can
+    // change the path reported for it
+    let binary =
+        env::current_exe().context("could not find the running binary")?;
+    // define crate paths relative to current directory
+    let module = format!("day{day:02}");
+    let source_path = PathBuf::from("solutions")
+        .join("src")
+        .join(format!("{module}.rs"));
+    let examples_dir = PathBuf::from("solutions")
+        .join("tests")
+        .join("examples")
+        .join(module);
+
+    println!(
+        "Watching day {day} (input {}, examples in {}); press Ctrl-C to stop",
+        input_path.display(),
+        examples_dir.display()
+    );
+    let mut previous = BTreeMap::new();
+    let mut snapshot = Snapshot::default();
+    let mut source_snapshot = Snapshot::take(slice::from_ref(&source_path));
+    loop {
+        let mut paths = vec![input_path.clone()];
+        paths.extend(example_inputs(&examples_dir));
+        let current = Snapshot::take(&paths);
+        for path in current.changed_since(&snapshot) {
+            watch_run(day, path, options, min_timing, &mut previous);
+        }
+        snapshot = current;
 
-    fn part_output_timed(
-        &mut self,
-        part: SolutionPart,
-        output: &dyn Display,
-        duration: Duration,
-    ) {
-        if self.duration_over_min(duration) {
-            println!("{} ({})", output, format_duration(duration));
-        } else {
-            self.part_output(part, output);
+        let current_source = Snapshot::take(slice::from_ref(&source_path));
+        if current_source
+            .changed_since(&source_snapshot)
+            .next()
+            .is_some()
+        {
+            println!("{} changed: rebuilding", source_path.display());
+            match rebuild() {
+                Ok(()) => return restart(&binary),
+                Err(error) => println!("{error:#}\n"),
+            }
         }
+        source_snapshot = current_source;
+
+        thread::sleep(interval);
+    }
+}
+
+/// Print the days with registered solutions and their names.
+fn list_solutions() {
+    for entry in SOLUTIONS.entries() {
+        println!("{:>2}: {}", entry.day, entry.name);
     }
 }
 
 fn main() -> Result<()> {
     let args = Cli::parse();
-    let input_text = get_input(args.day, args.input)?;
-    let mut handler =
-        CliOutputHandler::new(Duration::from_millis(args.min_timing_ms));
-    run_day(args.day, &mut handler, &input_text, args.timed)
-        .with_context(|| "failed to run solution")
+    if let Some(command) = args.command {
+        return match command {
+            Command::Fetch(fetch_args) => fetch_inputs(fetch_args),
+            Command::Submit(submit_args) => submit_answer(submit_args),
+            Command::New(new_args) => new_solution(new_args),
+        };
+    }
+    if args.list {
+        list_solutions();
+        return Ok(());
+    }
+
+    let mut handler: Box<dyn CliHandler> = match args.output {
+        OutputFormat::Text => Box::new(CliOutputHandler::new(
+            Duration::from_millis(args.min_timing_ms),
+        )),
+        OutputFormat::Json => Box::new(JsonOutputHandler::new(false)),
+        OutputFormat::Jsonl => Box::new(JsonOutputHandler::new(true)),
+    };
+
+    if args.check && args.input.is_some() && args.answers.is_none() {
+        bail!(
+            "checking an alternative input file needs expected answers for \
+            it, provided with the answers file argument"
+        );
+    }
+    let answers = if args.check {
+        Some(load_answers(args.answers)?)
+    } else {
+        None
+    };
+
+    let parts = args.part.map_or(PartSelection::All, |number| {
+        PartSelection::Only(part_from_number(number))
+    });
+    let bench = args.bench.map(|runs| BenchOptions {
+        warmup: args.warmup,
+        runs,
+        parts,
+    });
+    let options = RunOptions {
+        parts,
+        timed: args.timed,
+        answers: answers.as_ref(),
+    };
+
+    let days: Vec<u8> = if args.all {
+        puzzle_days().collect()
+    } else {
+        args.days
+            .map(|selection| selection.days().to_vec())
+            .unwrap_or_default()
+    };
+
+    if args.watch {
+        let [day] = days[..] else {
+            bail!("watch mode can only run a single day");
+        };
+        return watch_day(
+            day,
+            args.input,
+            options,
+            Duration::from_millis(args.min_timing_ms),
+            Duration::from_millis(args.poll_ms),
+        );
+    }
+
+    if let [day] = days[..]
+        && !args.all
+    {
+        get_solution(day)?;
+        let input_text = get_input(day, args.input)?;
+        if let Some(bench) = bench {
+            return bench_single(day, handler.as_mut(), &input_text, bench);
+        }
+        return run_single(day, handler.as_mut(), &input_text, options);
+    }
+
+    if args.input.is_some() {
+        bail!("an input file can only be provided when running a single day");
+    }
+    if let Some(bench) = bench {
+        return bench_batch(&days, handler.as_mut(), bench);
+    }
+    run_batch(&days, handler.as_mut(), options)
 }
@@ -0,0 +1,179 @@
+//! Output handlers for the CLI.
+
+use std::fmt::Display;
+use std::time::Duration;
+
+use aoc_framework::bench::BenchReport;
+use aoc_framework::{OutputHandler, SolutionPart};
+use clap::ValueEnum;
+
+use crate::bench::format_bench;
+use crate::check::{CheckStatus, PartCheck};
+use crate::format::format_duration;
+use crate::summary::{DayRecord, format_summary};
+
+mod json;
+
+pub use json::JsonOutputHandler;
+
+/// The format of output printed by the CLI.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, ValueEnum)]
+pub enum OutputFormat {
+    /// Human readable text.
+    #[default]
+    Text,
+    /// A JSON document printed after all days have run.
+    Json,
+    /// JSON Lines, printing a record per event as it happens.
+    Jsonl,
+}
+
+/// An output handler with extra events for the CLI running days.
+pub trait CliHandler: OutputHandler {
+    /// Called before running a day's solution.
+    fn day_start(&mut self, day: u8);
+
+    /// Called when a day is skipped, with a reason for display.
+    fn day_skipped(&mut self, day: u8, reason: &str);
+
+    /// Called when a day's solution fails, with the error.
+    fn day_failed(&mut self, day: u8, error: &anyhow::Error);
+
+    /// Called after running a day with checked answers, with the check of
+    /// each part output.
+    fn day_checked(&mut self, day: u8, checks: &[PartCheck]);
+
+    /// Called after benchmarking a day, with the report of each phase.
+    fn day_bench(&mut self, day: u8, report: &BenchReport);
+
+    /// Called after running a batch of days, with records of each day.
+    fn summary(&mut self, records: &[DayRecord]);
+
+    /// Called when the CLI is finished running days.
+    ///
+    /// # Errors
+    ///
+    /// If writing buffered output fails, an error is returned.
+    fn finish(&mut self) -> anyhow::Result<()>;
+}
+
+/// The output event handler for the Advent of Code CLI.
+///
+/// This tracks a minimum timing threshold to control printing timing
+/// information. Any durations under the minimum will be omitted.
+pub struct CliOutputHandler {
+    /// The minimum timing threshold.
+    min_timing: Duration,
+    /// Whether output for a day has been printed, to separate the next day.
+    printed_day: bool,
+}
+
+impl CliOutputHandler {
+    /// Construct an instance with the given minimum timing threshold.
+    #[must_use]
+    pub fn new(min_timing: Duration) -> Self {
+        Self {
+            min_timing,
+            printed_day: false,
+        }
+    }
+
+    /// Print an empty line to separate from any previous day's output.
+    fn separate_day(&mut self) {
+        if self.printed_day {
+            println!();
+        }
+        self.printed_day = true;
+    }
+
+    /// Check if the given duration is above the minimum timing.
+    fn duration_over_min(&self, duration: Duration) -> bool {
+        duration >= self.min_timing
+    }
+}
+
+impl OutputHandler for CliOutputHandler {
+    fn solution_name(&mut self, name: &str) {
+        println!("= {name} =");
+    }
+
+    fn parse_start(&mut self) {
+        // do nothing
+    }
+
+    fn parse_end(&mut self) {
+        // do nothing
+    }
+
+    fn parse_end_timed(&mut self, duration: Duration) {
+        if self.duration_over_min(duration) {
+            println!("Input parsed in {}", format_duration(duration));
+        }
+    }
+
+    fn part_start(&mut self, part: SolutionPart) {
+        println!("-- {} --", part.default_name());
+    }
+
+    fn part_output(&mut self, _part: SolutionPart, output: &dyn Display) {
+        println!("{output}");
+    }
+
+    fn part_output_timed(
+        &mut self,
+        part: SolutionPart,
+        output: &dyn Display,
+        duration: Duration,
+    ) {
+        if self.duration_over_min(duration) {
+            println!("{} ({})", output, format_duration(duration));
+        } else {
+            self.part_output(part, output);
+        }
+    }
+}
+
+impl CliHandler for CliOutputHandler {
+    fn day_start(&mut self, _day: u8) {
+        self.separate_day();
+    }
+
+    fn day_skipped(&mut self, day: u8, reason: &str) {
+        self.separate_day();
+        println!("Skipping day {day}: {reason}");
+    }
+
+    fn day_failed(&mut self, day: u8, error: &anyhow::Error) {
+        println!("Day {day} failed: {error:#}");
+    }
+
+    fn day_checked(&mut self, _day: u8, checks: &[PartCheck]) {
+        println!("-- Check --");
+        for check in checks {
+            let part = check.part.default_name();
+            match &check.status {
+                CheckStatus::Pass => println!("{part}: pass"),
+                CheckStatus::Fail { expected } => {
+                    println!("{part}: FAIL (expected {expected})");
+                }
+                CheckStatus::Unknown => {
+                    println!("{part}: unknown (no expected answer)");
+                }
+            }
+        }
+    }
+
+    fn day_bench(&mut self, _day: u8, report: &BenchReport) {
+        print!("{}", format_bench(report));
+    }
+
+    fn summary(&mut self, records: &[DayRecord]) {
+        self.separate_day();
+        println!("= Summary =");
+        print!("{}", format_summary(records));
+    }
+
+    fn finish(&mut self) -> anyhow::Result<()> {
+        Ok(())
+    }
+}
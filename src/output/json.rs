@@ -0,0 +1,542 @@
+//! A JSON output handler for the CLI.
+
+use std::fmt::Display;
+use std::io::{self, Write};
+use std::time::Duration;
+
+use anyhow::{Context, Result};
+use aoc_framework::bench::{BenchPhase, BenchReport, PhaseStats};
+use aoc_framework::{OutputHandler, SolutionPart};
+use serde::Serialize;
+
+use crate::check::{CheckStatus, PartCheck};
+use crate::output::CliHandler;
+use crate::summary::DayRecord;
+
+/// Convert a duration to whole nanoseconds, saturating on overflow.
+fn duration_nanos(duration: Duration) -> u64 {
+    u64::try_from(duration.as_nanos()).unwrap_or(u64::MAX)
+}
+
+/// Get the number of a part for output.
+fn part_number(part: SolutionPart) -> u8 {
+    match part {
+        SolutionPart::Part1 => 1,
+        SolutionPart::Part2 => 2,
+    }
+}
+
+/// The outcome of running a day, as output.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+enum Status {
+    Completed,
+    Skipped,
+    Failed,
+}
+
+/// The verdict of checking a part's answer, as output.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+enum Check {
+    Pass,
+    Fail,
+    Unknown,
+}
+
+impl Check {
+    /// Split a check status into its verdict and any expected answer.
+    fn from_status(status: &CheckStatus) -> (Self, Option<&str>) {
+        match status {
+            CheckStatus::Pass => (Self::Pass, None),
+            CheckStatus::Fail { expected } => (Self::Fail, Some(expected)),
+            CheckStatus::Unknown => (Self::Unknown, None),
+        }
+    }
+}
+
+/// The answer of a part, as output.
+#[derive(Debug, Clone, Serialize)]
+struct PartOutput {
+    part: u8,
+    answer: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    duration_ns: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    check: Option<Check>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    expected: Option<String>,
+}
+
+/// The statistics of a benchmarked phase, as output.
+#[derive(Debug, Clone, Serialize)]
+struct PhaseOutput {
+    phase: String,
+    runs: usize,
+    min_ns: u64,
+    median_ns: u64,
+    mean_ns: u64,
+    p95_ns: u64,
+    stddev_ns: u64,
+}
+
+impl From<&PhaseStats> for PhaseOutput {
+    fn from(phase: &PhaseStats) -> Self {
+        let stats = &phase.stats;
+        Self {
+            phase: match phase.phase {
+                BenchPhase::Parse => "parse".into(),
+                BenchPhase::Part(part) => format!("part{}", part_number(part)),
+            },
+            runs: stats.runs,
+            min_ns: duration_nanos(stats.min),
+            median_ns: duration_nanos(stats.median),
+            mean_ns: duration_nanos(stats.mean),
+            p95_ns: duration_nanos(stats.p95),
+            stddev_ns: duration_nanos(stats.stddev),
+        }
+    }
+}
+
+/// A record of running a solution, as output.
+#[derive(Debug, Clone, Serialize)]
+struct SolutionOutput {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    day: Option<u8>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<String>,
+    status: Status,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    message: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    parse_duration_ns: Option<u64>,
+    parts: Vec<PartOutput>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    bench: Option<Vec<PhaseOutput>>,
+}
+
+impl SolutionOutput {
+    /// Create a record for a day with no recorded events yet.
+    fn new(day: Option<u8>, name: Option<String>, status: Status) -> Self {
+        Self {
+            day,
+            name,
+            status,
+            message: None,
+            parse_duration_ns: None,
+            parts: Vec::new(),
+            bench: None,
+        }
+    }
+}
+
+/// The document output after running all days.
+#[derive(Debug, Serialize)]
+struct Document<'a> {
+    solutions: &'a [SolutionOutput],
+}
+
+/// An event output as a JSON Lines record.
+#[derive(Debug, Serialize)]
+#[serde(tag = "event", rename_all = "snake_case")]
+enum Event<'a> {
+    Solution {
+        #[serde(skip_serializing_if = "Option::is_none")]
+        day: Option<u8>,
+        name: &'a str,
+    },
+    Parse {
+        #[serde(skip_serializing_if = "Option::is_none")]
+        day: Option<u8>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        duration_ns: Option<u64>,
+    },
+    Part {
+        #[serde(skip_serializing_if = "Option::is_none")]
+        day: Option<u8>,
+        #[serde(flatten)]
+        output: &'a PartOutput,
+    },
+    Skipped {
+        day: u8,
+        reason: &'a str,
+    },
+    Failed {
+        day: u8,
+        error: &'a str,
+    },
+    Bench {
+        day: u8,
+        phases: &'a [PhaseOutput],
+    },
+    Check {
+        day: u8,
+        part: u8,
+        check: Check,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        expected: Option<&'a str>,
+    },
+}
+
+/// An output handler printing structured JSON records.
+///
+/// As a JSON document, records of each solution are collected and printed
+/// when finished. As JSON Lines, a record is printed per event as it happens.
+/// Durations are output as whole nanoseconds.
+///
+/// Output is written to stdout unless another writer is given.
+pub struct JsonOutputHandler<W: Write = io::Stdout> {
+    /// Where records are written.
+    writer: W,
+    /// Whether to print JSON Lines records per event.
+    lines: bool,
+    /// The day currently running, if known.
+    day: Option<u8>,
+    /// Records of solutions run.
+    solutions: Vec<SolutionOutput>,
+    /// The first error that occurred writing output.
+    error: Option<io::Error>,
+}
+
+impl JsonOutputHandler {
+    /// Construct a handler printing a JSON document, or JSON Lines if `lines`
+    /// is true.
+    #[must_use]
+    pub fn new(lines: bool) -> Self {
+        Self::with_writer(io::stdout(), lines)
+    }
+}
+
+impl<W: Write> JsonOutputHandler<W> {
+    /// Construct a handler writing a JSON document to a writer, or JSON Lines
+    /// if `lines` is true.
+    pub fn with_writer(writer: W, lines: bool) -> Self {
+        Self {
+            writer,
+            lines,
+            day: None,
+            solutions: Vec::new(),
+            error: None,
+        }
+    }
+
+    /// Print a JSON Lines record for an event, if printing lines.
+    fn emit(&mut self, event: &Event) {
+        if !self.lines || self.error.is_some() {
+            return;
+        }
+        let result = serde_json::to_writer(&mut self.writer, event)
+            .map_err(io::Error::from)
+            .and_then(|()| writeln!(self.writer));
+        if let Err(error) = result {
+            self.error = Some(error);
+        }
+    }
+
+    /// Get the record of the current solution, creating one if needed.
+    fn current(&mut self) -> &mut SolutionOutput {
+        let day = self.day;
+        if self.solutions.last().is_none_or(|record| record.day != day) {
+            self.solutions.push(SolutionOutput::new(
+                day,
+                None,
+                Status::Completed,
+            ));
+        }
+        let index = self.solutions.len() - 1;
+        &mut self.solutions[index]
+    }
+
+    /// Record the answer of a part.
+    fn record_part(
+        &mut self,
+        part: SolutionPart,
+        output: &dyn Display,
+        duration: Option<Duration>,
+    ) {
+        let output = PartOutput {
+            part: part_number(part),
+            answer: output.to_string(),
+            duration_ns: duration.map(duration_nanos),
+            check: None,
+            expected: None,
+        };
+        self.emit(&Event::Part {
+            day: self.day,
+            output: &output,
+        });
+        self.current().parts.push(output);
+    }
+}
+
+impl<W: Write> OutputHandler for JsonOutputHandler<W> {
+    fn solution_name(&mut self, name: &str) {
+        self.emit(&Event::Solution {
+            day: self.day,
+            name,
+        });
+        self.solutions.push(SolutionOutput::new(
+            self.day,
+            Some(name.into()),
+            Status::Completed,
+        ));
+    }
+
+    fn parse_start(&mut self) {
+        // do nothing
+    }
+
+    fn parse_end(&mut self) {
+        self.emit(&Event::Parse {
+            day: self.day,
+            duration_ns: None,
+        });
+    }
+
+    fn parse_end_timed(&mut self, duration: Duration) {
+        let duration_ns = duration_nanos(duration);
+        self.emit(&Event::Parse {
+            day: self.day,
+            duration_ns: Some(duration_ns),
+        });
+        self.current().parse_duration_ns = Some(duration_ns);
+    }
+
+    fn part_start(&mut self, _part: SolutionPart) {
+        // do nothing
+    }
+
+    fn part_output(&mut self, part: SolutionPart, output: &dyn Display) {
+        self.record_part(part, output, None);
+    }
+
+    fn part_output_timed(
+        &mut self,
+        part: SolutionPart,
+        output: &dyn Display,
+        duration: Duration,
+    ) {
+        self.record_part(part, output, Some(duration));
+    }
+}
+
+impl<W: Write> CliHandler for JsonOutputHandler<W> {
+    fn day_start(&mut self, day: u8) {
+        self.day = Some(day);
+    }
+
+    fn day_skipped(&mut self, day: u8, reason: &str) {
+        self.emit(&Event::Skipped { day, reason });
+        let mut record = SolutionOutput::new(Some(day), None, Status::Skipped);
+        record.message = Some(reason.into());
+        self.solutions.push(record);
+    }
+
+    fn day_failed(&mut self, day: u8, error: &anyhow::Error) {
+        let message = format!("{error:#}");
+        self.emit(&Event::Failed {
+            day,
+            error: &message,
+        });
+        self.day = Some(day);
+        let record = self.current();
+        record.status = Status::Failed;
+        record.message = Some(message);
+    }
+
+    fn day_checked(&mut self, day: u8, checks: &[PartCheck]) {
+        for check in checks {
+            let (verdict, expected) = Check::from_status(&check.status);
+            self.emit(&Event::Check {
+                day,
+                part: part_number(check.part),
+                check: verdict,
+                expected,
+            });
+        }
+
+        self.day = Some(day);
+        let record = self.current();
+        for check in checks {
+            let part = part_number(check.part);
+            if let Some(output) =
+                record.parts.iter_mut().find(|output| output.part == part)
+            {
+                let (verdict, expected) = Check::from_status(&check.status);
+                output.check = Some(verdict);
+                output.expected = expected.map(Into::into);
+            }
+        }
+    }
+
+    fn day_bench(&mut self, day: u8, report: &BenchReport) {
+        let phases: Vec<PhaseOutput> =
+            report.phases.iter().map(PhaseOutput::from).collect();
+        self.emit(&Event::Bench {
+            day,
+            phases: &phases,
+        });
+        self.day = Some(day);
+        self.current().bench = Some(phases);
+    }
+
+    fn summary(&mut self, _records: &[DayRecord]) {
+        // records are already output per solution
+    }
+
+    fn finish(&mut self) -> Result<()> {
+        if let Some(error) = self.error.take() {
+            return Err(error).context("failed to write JSON output");
+        }
+        if !self.lines {
+            serde_json::to_writer_pretty(
+                &mut self.writer,
+                &Document {
+                    solutions: &self.solutions,
+                },
+            )
+            .context("failed to write JSON output")?;
+            writeln!(self.writer).context("failed to write JSON output")?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn document_collects_solutions_by_day() -> Result<()> {
+        let mut handler = JsonOutputHandler::new(false);
+        handler.day_start(1);
+        handler.solution_name("Day 1: Secret Entrance");
+        handler.parse_end_timed(Duration::from_nanos(1_500));
+        handler.part_output_timed(
+            SolutionPart::Part1,
+            &3,
+            Duration::from_nanos(200),
+        );
+        handler.part_output(SolutionPart::Part2, &6);
+        handler.day_checked(
+            1,
+            &[
+                PartCheck {
+                    part: SolutionPart::Part1,
+                    answer: "3".into(),
+                    status: CheckStatus::Pass,
+                },
+                PartCheck {
+                    part: SolutionPart::Part2,
+                    answer: "6".into(),
+                    status: CheckStatus::Fail {
+                        expected: "7".into(),
+                    },
+                },
+            ],
+        );
+        handler.day_skipped(2, "missing input");
+        handler.day_start(3);
+        handler.solution_name("Day 3: Lobby");
+        handler.day_failed(3, &anyhow::anyhow!("bad input"));
+
+        let value = serde_json::to_value(Document {
+            solutions: &handler.solutions,
+        })?;
+        assert_eq!(
+            value,
+            json!({
+                "solutions": [
+                    {
+                        "day": 1,
+                        "name": "Day 1: Secret Entrance",
+                        "status": "completed",
+                        "parse_duration_ns": 1500,
+                        "parts": [
+                            {
+                                "part": 1,
+                                "answer": "3",
+                                "duration_ns": 200,
+                                "check": "pass"
+                            },
+                            {
+                                "part": 2,
+                                "answer": "6",
+                                "check": "fail",
+                                "expected": "7"
+                            }
+                        ]
+                    },
+                    {
+                        "day": 2,
+                        "status": "skipped",
+                        "message": "missing input",
+                        "parts": []
+                    },
+                    {
+                        "day": 3,
+                        "name": "Day 3: Lobby",
+                        "status": "failed",
+                        "message": "bad input",
+                        "parts": []
+                    }
+                ]
+            })
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn single_day_failure_is_recorded() -> Result<()> {
+        let mut handler = JsonOutputHandler::with_writer(Vec::new(), false);
+        let result = crate::run_single(
+            1,
+            &mut handler,
+            "X42\n",
+            crate::RunOptions {
+                parts: aoc_framework::PartSelection::All,
+                timed: false,
+                answers: None,
+            },
+        );
+        assert!(result.is_err(), "expected day to fail");
+
+        let value: serde_json::Value = serde_json::from_slice(&handler.writer)?;
+        let solution = &value["solutions"][0];
+        assert_eq!(solution["day"], 1);
+        assert_eq!(solution["status"], "failed");
+        assert!(
+            solution["message"].as_str().is_some_and(|m| !m.is_empty()),
+            "expected failure message, got: {solution}"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn part_event_serializes_flat() -> Result<()> {
+        let output = PartOutput {
+            part: 2,
+            answer: "40".into(),
+            duration_ns: Some(12),
+            check: None,
+            expected: None,
+        };
+        let value = serde_json::to_value(Event::Part {
+            day: Some(7),
+            output: &output,
+        })?;
+        assert_eq!(
+            value,
+            json!({
+                "event": "part",
+                "day": 7,
+                "part": 2,
+                "answer": "40",
+                "duration_ns": 12
+            })
+        );
+        Ok(())
+    }
+}
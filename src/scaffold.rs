@@ -0,0 +1,492 @@
+//! Generation of new day modules in the solutions crate.
+
+use std::path::{Path, PathBuf};
+use std::{fs, io};
+
+use thiserror::Error;
+
+/// The marker comment before the list of exported solution modules.
+const EXPORT_MARKER: &str = "// --- EXPORT SOLUTION MODULES HERE ---";
+
+/// The marker comment before the list of registered solutions.
+const REGISTER_MARKER: &str = "// --- REGISTER SOLUTIONS HERE ---";
+
+/// The marker comment before the list of solutions checked against examples.
+const EXAMPLES_MARKER: &str = "// --- CHECK EXAMPLES HERE ---";
+
+/// An error generating a day module.
+#[derive(Error, Debug)]
+pub enum ScaffoldError {
+    /// A file or directory to generate already exists.
+    #[error("{} already exists", .path.display())]
+    Exists {
+        /// The path that exists.
+        path: PathBuf,
+    },
+
+    /// A file to update didn't have the marker comment to insert after.
+    #[error("could not find {marker:?} in {}", .path.display())]
+    MissingMarker {
+        /// The path of the file.
+        path: PathBuf,
+        /// The marker comment.
+        marker: &'static str,
+    },
+
+    /// A file to update already lists the day.
+    #[error("day {day} is already listed in {}", .path.display())]
+    AlreadyListed {
+        /// The day.
+        day: u8,
+        /// The path of the file.
+        path: PathBuf,
+    },
+
+    /// Reading or writing a file failed.
+    #[error("could not access {}", .path.display())]
+    Io {
+        /// The path of the file.
+        path: PathBuf,
+        source: io::Error,
+    },
+}
+
+/// Options for generating a day module.
+#[derive(Debug, Clone)]
+pub struct ScaffoldOptions {
+    /// The day of the puzzle.
+    pub day: u8,
+    /// The title of the puzzle, if known.
+    pub title: Option<String>,
+    /// Whether to implement parts with a distinct parsing step.
+    pub parsed: bool,
+}
+
+/// The ordinal words of puzzle days, for documentation.
+const ORDINALS: [&str; 25] = [
+    "first",
+    "second",
+    "third",
+    "fourth",
+    "fifth",
+    "sixth",
+    "seventh",
+    "eighth",
+    "ninth",
+    "tenth",
+    "eleventh",
+    "twelfth",
+    "thirteenth",
+    "fourteenth",
+    "fifteenth",
+    "sixteenth",
+    "seventeenth",
+    "eighteenth",
+    "nineteenth",
+    "twentieth",
+    "twenty-first",
+    "twenty-second",
+    "twenty-third",
+    "twenty-fourth",
+    "twenty-fifth",
+];
+
+/// Render the source of a new day module.
+///
+/// The module implements part 1 with a `todo!()`, through
+/// [`ParsedPart1`][aoc_framework::ParsedPart1] if parsed, else
+/// [`Part1`][aoc_framework::Part1].
+#[must_use]
+pub fn render_module(options: &ScaffoldOptions) -> String {
+    let day = options.day;
+    let name = options.title.as_ref().map_or_else(
+        || format!("Day {day}"),
+        |title| format!("Day {day}: {title}"),
+    );
+    let name = format!("{name:?}");
+    let ordinal = usize::from(day)
+        .checked_sub(1)
+        .and_then(|index| ORDINALS.get(index))
+        .map_or_else(
+            || format!("day {day}"),
+            |ordinal| format!("{ordinal} day"),
+        );
+
+    let (import, part1) = if options.parsed {
+        (
+            "use aoc_framework::{
+    ParseResult, ParsedPart1, SolutionName, impl_runnable_solution,
+};",
+            format!(
+                "impl ParsedPart1 for Day{day:02} {{
+    type ParsedInput = Vec<String>;
+
+    fn parse(input: &str) -> ParseResult<Self::ParsedInput> {{
+        Ok(input.lines().map(String::from).collect())
+    }}
+
+    type Part1Output = usize;
+
+    fn part1(_parsed: &Self::ParsedInput) -> Self::Part1Output {{
+        todo!(\"solve part 1\")
+    }}
+}}
+
+// TODO implement part 2 with ParsedPart2 and run it
+impl_runnable_solution!(Day{day:02} => ParsedPart1);
+"
+            ),
+        )
+    } else {
+        (
+            "use aoc_framework::{ParseResult, Part1, SolutionName, \
+            impl_runnable_solution};",
+            format!(
+                "impl Part1 for Day{day:02} {{
+    type Part1Output = usize;
+
+    fn part1(_input: &str) -> ParseResult<Self::Part1Output> {{
+        todo!(\"solve part 1\")
+    }}
+}}
+
+// TODO implement part 2 with Part2 and run it
+impl_runnable_solution!(Day{day:02} => Part1);
+"
+            ),
+        )
+    };
+
+    format!(
+        "{import}
+
+/// Solution for the {ordinal}'s puzzle.
+///
+/// # Input
+///
+/// TODO describe the input.
+///
+/// # Part 1
+///
+/// TODO describe part 1.
+pub struct Day{day:02};
+
+impl SolutionName for Day{day:02} {{
+    const NAME: &'static str = {name};
+}}
+
+{part1}"
+    )
+}
+
+/// Render the expected answers file of a new day's examples.
+#[must_use]
+pub fn render_expected() -> String {
+    String::from(
+        "# expected answers of example.txt, as strings or integers
+[example]
+# part1 = 0
+# part2 = 0
+",
+    )
+}
+
+/// Insert a line for a day into the list following a marker comment,
+/// keeping the list ordered by day.
+///
+/// The list is the lines after the marker that `day_of` finds a day in. The
+/// new line is indented like the marker.
+fn insert_day_line(
+    text: &str,
+    marker: &'static str,
+    day: u8,
+    line: &str,
+    day_of: impl Fn(&str) -> Option<u8>,
+    path: &Path,
+) -> Result<String, ScaffoldError> {
+    let lines: Vec<&str> = text.lines().collect();
+    let marker_index = lines
+        .iter()
+        .position(|line| line.trim() == marker)
+        .ok_or_else(|| ScaffoldError::MissingMarker {
+            path: path.into(),
+            marker,
+        })?;
+    let indent = &lines[marker_index]
+        [..lines[marker_index].len() - lines[marker_index].trim_start().len()];
+
+    let mut insert_index = marker_index + 1;
+    for (index, listed) in lines.iter().enumerate().skip(marker_index + 1) {
+        let Some(listed_day) = day_of(listed) else {
+            break;
+        };
+        if listed_day == day {
+            return Err(ScaffoldError::AlreadyListed {
+                day,
+                path: path.into(),
+            });
+        }
+        if listed_day > day {
+            break;
+        }
+        insert_index = index + 1;
+    }
+
+    let mut updated: Vec<String> =
+        lines.iter().map(|line| (*line).to_string()).collect();
+    updated.insert(insert_index, format!("{indent}{line}"));
+    let mut updated = updated.join("\n");
+    if text.ends_with('\n') {
+        updated.push('\n');
+    }
+    Ok(updated)
+}
+
+/// Get the day of a module export line, like `pub mod day01;`.
+fn exported_day(line: &str) -> Option<u8> {
+    line.trim()
+        .strip_prefix("pub mod day")?
+        .strip_suffix(';')?
+        .parse()
+        .ok()
+}
+
+/// Get the day of a registration line, like
+/// `SolutionEntry::new::<day01::Day01>(1),`.
+fn registered_day(line: &str) -> Option<u8> {
+    let entry = line.trim().strip_prefix("SolutionEntry::new::<")?;
+    let (_, day) = entry.rsplit_once('(')?;
+    day.strip_suffix("),")?.parse().ok()
+}
+
+/// Get the day of an example check line, like
+/// `day01 => solutions::day01::Day01,`, which may start with attributes.
+fn checked_day(line: &str) -> Option<u8> {
+    let entry = line.trim();
+    let entry = entry.rsplit_once("] ").map_or(entry, |(_, entry)| entry);
+    let (name, _) = entry.split_once(" => ")?;
+    name.strip_prefix("day")?.parse().ok()
+}
+
+/// Read a file to a string.
+fn read(path: &Path) -> Result<String, ScaffoldError> {
+    fs::read_to_string(path).map_err(|source| ScaffoldError::Io {
+        path: path.into(),
+        source,
+    })
+}
+
+/// Write a string to a file, creating parent directories.
+fn write(path: &Path, contents: &str) -> Result<(), ScaffoldError> {
+    let io_error = |source| ScaffoldError::Io {
+        path: path.into(),
+        source,
+    };
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).map_err(io_error)?;
+    }
+    fs::write(path, contents).map_err(io_error)
+}
+
+/// Generate a new day module in a solutions crate, wiring it into the crate
+/// and its example tests.
+///
+/// The module is exported and registered in the crate's `lib.rs`, and an
+/// example case skeleton is created and checked (ignored until filled in)
+/// in `tests/examples.rs`. Every file is prepared before any is written, so
+/// a failure leaves the crate unchanged.
+///
+/// Returns the paths of files created or updated.
+///
+/// # Errors
+///
+/// If the module or examples already exist, a file is missing its marker
+/// comment, or files can't be read or written, an error is returned.
+pub fn scaffold(
+    crate_dir: &Path,
+    options: &ScaffoldOptions,
+) -> Result<Vec<PathBuf>, ScaffoldError> {
+    let day = options.day;
+    let module = format!("day{day:02}");
+
+    let module_path = crate_dir.join("src").join(format!("{module}.rs"));
+    let examples_dir = crate_dir.join("tests").join("examples").join(&module);
+    for path in [&module_path, &examples_dir] {
+        if path.exists() {
+            return Err(ScaffoldError::Exists { path: path.clone() });
+        }
+    }
+
+    let lib_path = crate_dir.join("src").join("lib.rs");
+    let lib = read(&lib_path)?;
+    let lib = insert_day_line(
+        &lib,
+        EXPORT_MARKER,
+        day,
+        &format!("pub mod {module};"),
+        exported_day,
+        &lib_path,
+    )?;
+    let lib = insert_day_line(
+        &lib,
+        REGISTER_MARKER,
+        day,
+        &format!("SolutionEntry::new::<{module}::Day{day:02}>({day}),"),
+        registered_day,
+        &lib_path,
+    )?;
+
+    let checks_path = crate_dir.join("tests").join("examples.rs");
+    let checks = insert_day_line(
+        &read(&checks_path)?,
+        EXAMPLES_MARKER,
+        day,
+        &format!(
+            "#[ignore = \"still working on solution\"] \
+            {module} => solutions::{module}::Day{day:02},"
+        ),
+        checked_day,
+        &checks_path,
+    )?;
+
+    let example_path = examples_dir.join("example.txt");
+    let expected_path = examples_dir.join("expected.toml");
+    let files = [
+        (module_path, render_module(options)),
+        (lib_path, lib),
+        (checks_path, checks),
+        (example_path, String::new()),
+        (expected_path, render_expected()),
+    ];
+    for (path, contents) in &files {
+        write(path, contents)?;
+    }
+    Ok(files.into_iter().map(|(path, _)| path).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const LIB: &str = "pub mod util;
+
+// --- EXPORT SOLUTION MODULES HERE ---
+pub mod day01;
+pub mod day03;
+
+pub static SOLUTIONS: Registry = Registry::new(&[
+    // --- REGISTER SOLUTIONS HERE ---
+    SolutionEntry::new::<day01::Day01>(1),
+    SolutionEntry::new::<day03::Day03>(3),
+]);
+";
+
+    fn insert_lib(day: u8) -> Result<String, ScaffoldError> {
+        let path = Path::new("lib.rs");
+        let module = format!("day{day:02}");
+        let lib = insert_day_line(
+            LIB,
+            EXPORT_MARKER,
+            day,
+            &format!("pub mod {module};"),
+            exported_day,
+            path,
+        )?;
+        insert_day_line(
+            &lib,
+            REGISTER_MARKER,
+            day,
+            &format!("SolutionEntry::new::<{module}::Day{day:02}>({day}),"),
+            registered_day,
+            path,
+        )
+    }
+
+    #[test]
+    fn inserts_day_lines_in_order() {
+        let lib = insert_lib(2).expect("expected to insert");
+        assert!(
+            lib.contains("pub mod day01;\npub mod day02;\npub mod day03;\n"),
+            "{lib}"
+        );
+        assert!(
+            lib.contains(
+                "(1),\n    SolutionEntry::new::<day02::Day02>(2),\n    \
+                SolutionEntry::new::<day03::Day03>(3),"
+            ),
+            "{lib}"
+        );
+
+        let lib = insert_lib(12).expect("expected to insert");
+        assert!(lib.contains("pub mod day03;\npub mod day12;\n\n"), "{lib}");
+        assert!(
+            lib.contains(
+                "(3),\n    SolutionEntry::new::<day12::Day12>(12),\n]"
+            ),
+            "{lib}"
+        );
+    }
+
+    #[test]
+    fn errors_on_listed_day_or_missing_marker() {
+        assert!(matches!(
+            insert_lib(3),
+            Err(ScaffoldError::AlreadyListed { day: 3, .. })
+        ));
+        let result = insert_day_line(
+            "pub mod day01;\n",
+            EXPORT_MARKER,
+            2,
+            "pub mod day02;",
+            exported_day,
+            Path::new("lib.rs"),
+        );
+        assert!(matches!(result, Err(ScaffoldError::MissingMarker { .. })));
+    }
+
+    #[test]
+    fn finds_days_of_checked_examples() {
+        assert_eq!(
+            checked_day("    day04 => solutions::day04::Day04,"),
+            Some(4)
+        );
+        assert_eq!(
+            checked_day(
+                "    #[ignore = \"still working on solution\"] \
+                day12 => solutions::day12::Day12,"
+            ),
+            Some(12)
+        );
+        assert_eq!(checked_day("}"), None);
+    }
+
+    #[test]
+    fn renders_module_with_title() {
+        let module = render_module(&ScaffoldOptions {
+            day: 12,
+            title: Some("Tree \"Farm\"".into()),
+            parsed: true,
+        });
+        assert!(module.contains("the twelfth day's puzzle"), "{module}");
+        assert!(module.contains("pub struct Day12;"), "{module}");
+        assert!(
+            module.contains(
+                "const NAME: &'static str = \"Day 12: Tree \\\"Farm\\\"\";"
+            ),
+            "{module}"
+        );
+        assert!(module.contains("impl ParsedPart1 for Day12"), "{module}");
+        assert!(
+            module.contains("impl_runnable_solution!(Day12 => ParsedPart1);"),
+            "{module}"
+        );
+
+        let module = render_module(&ScaffoldOptions {
+            day: 3,
+            title: None,
+            parsed: false,
+        });
+        assert!(module.contains("\"Day 3\";"), "{module}");
+        assert!(module.contains("impl Part1 for Day03"), "{module}");
+    }
+}
@@ -0,0 +1,517 @@
+//! Interpreting answer submissions and remembering wrong guesses.
+
+use std::collections::BTreeMap;
+use std::fmt::{self, Display};
+use std::str::FromStr;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use aoc_framework::SolutionPart;
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+/// How a wrong answer compared to the right answer, if the site said.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum WrongKind {
+    /// The answer was wrong without a hint.
+    Wrong,
+    /// The answer was higher than the right answer.
+    TooHigh,
+    /// The answer was lower than the right answer.
+    TooLow,
+}
+
+impl Display for WrongKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Wrong => write!(f, "wrong"),
+            Self::TooHigh => write!(f, "too high"),
+            Self::TooLow => write!(f, "too low"),
+        }
+    }
+}
+
+/// The verdict of submitting an answer, parsed from the site's response.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    /// The answer was right.
+    Correct,
+    /// The answer was wrong.
+    Wrong(WrongKind),
+    /// An answer was submitted too recently, with the time left to wait.
+    Wait(Option<Duration>),
+    /// The part was already solved, or isn't unlocked yet.
+    WrongLevel,
+    /// The response wasn't recognized. Contains the response text.
+    Unrecognized(String),
+}
+
+impl Verdict {
+    /// Parse the verdict from the HTML page responding to a submission.
+    ///
+    /// The message is read from the page's `<article>` element when present,
+    /// with tags stripped.
+    #[must_use]
+    pub fn from_response(html: &str) -> Self {
+        let text = response_text(html);
+
+        if text.contains("That's the right answer") {
+            Self::Correct
+        } else if text.contains("That's not the right answer") {
+            if text.contains("too high") {
+                Self::Wrong(WrongKind::TooHigh)
+            } else if text.contains("too low") {
+                Self::Wrong(WrongKind::TooLow)
+            } else {
+                Self::Wrong(WrongKind::Wrong)
+            }
+        } else if text.contains("You gave an answer too recently") {
+            Self::Wait(parse_wait(&text))
+        } else if text.contains("You don't seem to be solving the right level")
+        {
+            Self::WrongLevel
+        } else {
+            Self::Unrecognized(text)
+        }
+    }
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Correct => write!(f, "correct answer"),
+            Self::Wrong(WrongKind::Wrong) => write!(f, "wrong answer"),
+            Self::Wrong(kind) => write!(f, "wrong answer, {kind}"),
+            Self::Wait(Some(left)) => write!(
+                f,
+                "answer submitted too recently, wait {}s",
+                left.as_secs()
+            ),
+            Self::Wait(None) => write!(f, "answer submitted too recently"),
+            Self::WrongLevel => {
+                write!(f, "part is already solved or not unlocked")
+            }
+            Self::Unrecognized(text) => {
+                write!(f, "unrecognized response: {text}")
+            }
+        }
+    }
+}
+
+/// Get the text of a response's message, stripping tags and collapsing
+/// whitespace.
+fn response_text(html: &str) -> String {
+    let article = html
+        .split_once("<article")
+        .and_then(|(_, rest)| rest.split_once('>'))
+        .and_then(|(_, rest)| rest.split_once("</article>"))
+        .map_or(html, |(article, _)| article);
+
+    let mut text = String::with_capacity(article.len());
+    let mut in_tag = false;
+    for character in article.chars() {
+        match character {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            _ if !in_tag => text.push(character),
+            _ => {}
+        }
+    }
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// Parse the time left to wait from a message like "You have 1m 5s left to
+/// wait".
+fn parse_wait(text: &str) -> Option<Duration> {
+    let (_, rest) = text.split_once("You have ")?;
+    let (left, _) = rest.split_once(" left to wait")?;
+
+    let mut seconds = 0;
+    for amount in left.split_whitespace() {
+        let (number, multiplier) =
+            if let Some(number) = amount.strip_suffix('h') {
+                (number, 3600)
+            } else if let Some(number) = amount.strip_suffix('m') {
+                (number, 60)
+            } else {
+                (amount.strip_suffix('s')?, 1)
+            };
+        seconds += number.parse::<u64>().ok()? * multiplier;
+    }
+    Some(Duration::from_secs(seconds))
+}
+
+/// A wrong answer submitted for a part.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Guess {
+    /// The submitted answer.
+    pub answer: String,
+    /// How the answer was wrong.
+    pub kind: WrongKind,
+}
+
+/// The wrong guesses of a day's parts as written in the guesses file.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct DayGuesses {
+    /// When the site allows submitting again, in seconds since the Unix
+    /// epoch.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    wait_until: Option<u64>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    part1: Vec<Guess>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    part2: Vec<Guess>,
+}
+
+impl DayGuesses {
+    /// Get the wrong guesses of a part.
+    fn get(&self, part: SolutionPart) -> &[Guess] {
+        match part {
+            SolutionPart::Part1 => &self.part1,
+            SolutionPart::Part2 => &self.part2,
+        }
+    }
+
+    /// Get the wrong guesses of a part to modify.
+    fn get_mut(&mut self, part: SolutionPart) -> &mut Vec<Guess> {
+        match part {
+            SolutionPart::Part1 => &mut self.part1,
+            SolutionPart::Part2 => &mut self.part2,
+        }
+    }
+}
+
+/// An answer that is known to be wrong from previous guesses.
+#[derive(Error, Debug, PartialEq, Eq)]
+pub enum KnownWrong {
+    /// The answer was already submitted.
+    #[error("{answer} was already submitted and was {kind}")]
+    Repeated {
+        /// The answer.
+        answer: String,
+        /// How the answer was wrong.
+        kind: WrongKind,
+    },
+
+    /// The answer is no lower than a guess that was too high.
+    #[error("{answer} is at least {guess}, which was too high")]
+    AboveTooHigh {
+        /// The answer.
+        answer: String,
+        /// The guess that was too high.
+        guess: String,
+    },
+
+    /// The answer is no higher than a guess that was too low.
+    #[error("{answer} is at most {guess}, which was too low")]
+    BelowTooLow {
+        /// The answer.
+        answer: String,
+        /// The guess that was too low.
+        guess: String,
+    },
+}
+
+/// A submission refused because the site asked to wait before submitting
+/// again.
+#[derive(Error, Debug, PartialEq, Eq)]
+#[error("the site asked to wait {}s more before submitting", .left.as_secs())]
+pub struct WaitPending {
+    /// The time left to wait.
+    pub left: Duration,
+}
+
+/// An error parsing a [`GuessLog`].
+#[derive(Error, Debug)]
+pub enum GuessesError {
+    /// The file was not valid TOML of the expected shape.
+    #[error(transparent)]
+    Toml(#[from] toml::de::Error),
+
+    /// A table was not named for a day, like `day01`.
+    #[error("invalid day table {key:?}, expected a name like \"day01\"")]
+    InvalidKey {
+        /// The name of the table.
+        key: String,
+    },
+}
+
+/// Wrong guesses of days' parts, stored as a TOML file so the same wrong
+/// answer is never submitted twice.
+///
+/// Each day is a table named `dayNN` with lists of guesses per part, and
+/// when the site last asked to wait, the time submitting is allowed again:
+///
+/// ```toml
+/// [day01]
+/// wait_until = 1764567890
+///
+/// [[day01.part1]]
+/// answer = "42"
+/// kind = "too-high"
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct GuessLog(BTreeMap<u8, DayGuesses>);
+
+impl GuessLog {
+    /// Check an answer against the wrong guesses of a part.
+    ///
+    /// Besides repeats, integer answers are compared against guesses that
+    /// were too high or too low.
+    ///
+    /// # Errors
+    ///
+    /// If the answer is known to be wrong, an error describing why is
+    /// returned.
+    pub fn check(
+        &self,
+        day: u8,
+        part: SolutionPart,
+        answer: &str,
+    ) -> Result<(), KnownWrong> {
+        let Some(guesses) = self.0.get(&day).map(|day| day.get(part)) else {
+            return Ok(());
+        };
+        let answer = answer.trim();
+
+        if let Some(guess) = guesses.iter().find(|guess| guess.answer == answer)
+        {
+            return Err(KnownWrong::Repeated {
+                answer: answer.into(),
+                kind: guess.kind,
+            });
+        }
+
+        let Ok(value) = answer.parse::<i128>() else {
+            return Ok(());
+        };
+        for guess in guesses {
+            let Ok(guess_value) = guess.answer.parse::<i128>() else {
+                continue;
+            };
+            match guess.kind {
+                WrongKind::TooHigh if value >= guess_value => {
+                    return Err(KnownWrong::AboveTooHigh {
+                        answer: answer.into(),
+                        guess: guess.answer.clone(),
+                    });
+                }
+                WrongKind::TooLow if value <= guess_value => {
+                    return Err(KnownWrong::BelowTooLow {
+                        answer: answer.into(),
+                        guess: guess.answer.clone(),
+                    });
+                }
+                _ => {}
+            }
+        }
+        Ok(())
+    }
+
+    /// Record a wrong guess of a part.
+    pub fn record(
+        &mut self,
+        day: u8,
+        part: SolutionPart,
+        answer: &str,
+        kind: WrongKind,
+    ) {
+        self.0.entry(day).or_default().get_mut(part).push(Guess {
+            answer: answer.trim().into(),
+            kind,
+        });
+    }
+
+    /// Check that the site isn't still asking to wait before submitting
+    /// another answer for a day.
+    ///
+    /// # Errors
+    ///
+    /// If a recorded wait hasn't passed by `now`, an error with the time left
+    /// is returned.
+    pub fn check_wait(
+        &self,
+        day: u8,
+        now: SystemTime,
+    ) -> Result<(), WaitPending> {
+        let Some(until) = self.0.get(&day).and_then(|day| day.wait_until)
+        else {
+            return Ok(());
+        };
+        let until = UNIX_EPOCH + Duration::from_secs(until);
+        match until.duration_since(now) {
+            Ok(left) if !left.is_zero() => Err(WaitPending { left }),
+            _ => Ok(()),
+        }
+    }
+
+    /// Record that the site asked to wait before submitting another answer
+    /// for a day, until the given time.
+    pub fn record_wait(&mut self, day: u8, until: SystemTime) {
+        let duration = until.duration_since(UNIX_EPOCH).unwrap_or_default();
+        // round up so the wait is never cut short
+        let seconds =
+            duration.as_secs() + u64::from(duration.subsec_nanos() > 0);
+        self.0.entry(day).or_default().wait_until = Some(seconds);
+    }
+}
+
+impl FromStr for GuessLog {
+    type Err = GuessesError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let tables: BTreeMap<String, DayGuesses> = toml::from_str(s)?;
+
+        let mut days = BTreeMap::new();
+        for (key, guesses) in tables {
+            let day = key
+                .strip_prefix("day")
+                .and_then(|number| number.parse().ok())
+                .ok_or(GuessesError::InvalidKey { key })?;
+            days.insert(day, guesses);
+        }
+        Ok(Self(days))
+    }
+}
+
+impl Display for GuessLog {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let tables: BTreeMap<String, &DayGuesses> = self
+            .0
+            .iter()
+            .map(|(day, guesses)| (format!("day{day:02}"), guesses))
+            .collect();
+        let text = toml::to_string(&tables).map_err(|_| fmt::Error)?;
+        f.write_str(&text)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_verdicts_from_responses() {
+        let page = |message: &str| {
+            format!(
+                "<html><body><main><article><p>{message}</p></article>\
+                </main></body></html>"
+            )
+        };
+
+        assert_eq!(
+            Verdict::from_response(&page(
+                "That's the right answer! You are one gold star closer."
+            )),
+            Verdict::Correct
+        );
+        assert_eq!(
+            Verdict::from_response(&page(
+                "That's not the right answer; your answer is too high. \
+                <a href=\"/2025/day/1\">[Return to Day 1]</a>"
+            )),
+            Verdict::Wrong(WrongKind::TooHigh)
+        );
+        assert_eq!(
+            Verdict::from_response(&page(
+                "That's not the right answer; your answer is too low."
+            )),
+            Verdict::Wrong(WrongKind::TooLow)
+        );
+        assert_eq!(
+            Verdict::from_response(&page(
+                "That's not the right answer. If you're stuck, ..."
+            )),
+            Verdict::Wrong(WrongKind::Wrong)
+        );
+        assert_eq!(
+            Verdict::from_response(&page(
+                "You gave an answer too recently; you have to wait after \
+                submitting an answer before trying again. You have 1m 5s \
+                left to wait."
+            )),
+            Verdict::Wait(Some(Duration::from_secs(65)))
+        );
+        assert_eq!(
+            Verdict::from_response(&page(
+                "You don't seem to be solving the right level. Did you \
+                already complete it?"
+            )),
+            Verdict::WrongLevel
+        );
+        assert_eq!(
+            Verdict::from_response("<p>Something <b>else</b></p>"),
+            Verdict::Unrecognized("Something else".into())
+        );
+    }
+
+    #[test]
+    fn guess_log_rejects_known_wrong_answers() {
+        let mut log = GuessLog::default();
+        log.record(1, SolutionPart::Part1, "100", WrongKind::TooHigh);
+        log.record(1, SolutionPart::Part1, "10", WrongKind::TooLow);
+        log.record(1, SolutionPart::Part2, "abc", WrongKind::Wrong);
+
+        assert_eq!(
+            log.check(1, SolutionPart::Part1, "10"),
+            Err(KnownWrong::Repeated {
+                answer: "10".into(),
+                kind: WrongKind::TooLow,
+            })
+        );
+        assert!(matches!(
+            log.check(1, SolutionPart::Part1, "150"),
+            Err(KnownWrong::AboveTooHigh { .. })
+        ));
+        assert!(matches!(
+            log.check(1, SolutionPart::Part1, "5"),
+            Err(KnownWrong::BelowTooLow { .. })
+        ));
+        assert_eq!(log.check(1, SolutionPart::Part1, "50"), Ok(()));
+        assert!(log.check(1, SolutionPart::Part2, "abc").is_err());
+        assert_eq!(log.check(1, SolutionPart::Part2, "abd"), Ok(()));
+        assert_eq!(log.check(2, SolutionPart::Part1, "10"), Ok(()));
+    }
+
+    #[test]
+    fn guess_log_round_trips_through_toml() {
+        let mut log = GuessLog::default();
+        log.record(3, SolutionPart::Part2, "42", WrongKind::TooHigh);
+
+        let text = log.to_string();
+        assert!(text.contains("[[day03.part2]]"), "{text}");
+        assert!(text.contains("kind = \"too-high\""), "{text}");
+        assert_eq!(text.parse::<GuessLog>().expect("expected to parse"), log);
+    }
+
+    #[test]
+    fn guess_log_remembers_waits() {
+        let now = UNIX_EPOCH + Duration::from_secs(1000);
+        let mut log = GuessLog::default();
+        log.record(3, SolutionPart::Part1, "42", WrongKind::Wrong);
+        log.record_wait(3, now + Duration::from_millis(59_500));
+
+        let text = log.to_string();
+        assert!(text.contains("wait_until = 1060"), "{text}");
+        let log = text.parse::<GuessLog>().expect("expected to parse");
+        assert_eq!(
+            log.check_wait(3, now),
+            Err(WaitPending {
+                left: Duration::from_mins(1)
+            })
+        );
+        assert_eq!(log.check_wait(3, now + Duration::from_mins(1)), Ok(()));
+        assert_eq!(log.check_wait(4, now), Ok(()));
+    }
+
+    #[test]
+    fn guess_log_errors_on_invalid_day_table() {
+        let result = "[[first.part1]]\nanswer = \"1\"\nkind = \"wrong\""
+            .parse::<GuessLog>();
+        match result {
+            Err(GuessesError::InvalidKey { key }) => assert_eq!(key, "first"),
+            other => panic!("unexpected result: {other:?}"),
+        }
+    }
+}
@@ -0,0 +1,317 @@
+//! Summary of running multiple days' solutions.
+
+use std::fmt::{Display, Write};
+use std::time::Duration;
+
+use aoc_framework::{OutputHandler, SolutionPart};
+
+use crate::format::format_duration;
+
+/// The answer of a part recorded while running a solution.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PartRecord {
+    /// The part that was run.
+    pub part: SolutionPart,
+    /// The displayed answer of the part.
+    pub answer: String,
+    /// The duration taken to run the part, if timed.
+    pub duration: Option<Duration>,
+}
+
+/// The outcome of running a day.
+#[derive(Debug, Clone, PartialEq)]
+pub enum DayStatus {
+    /// The day's solution ran to completion.
+    Completed,
+    /// The day was not run, with a reason for display.
+    Skipped(String),
+    /// The day's solution failed, with an error for display.
+    Failed(String),
+}
+
+/// A record of running a day's solution.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DayRecord {
+    /// The day that was run.
+    pub day: u8,
+    /// The outcome of running the day.
+    pub status: DayStatus,
+    /// The duration taken to parse input, if timed.
+    pub parse_duration: Option<Duration>,
+    /// The parts that output answers.
+    pub parts: Vec<PartRecord>,
+}
+
+impl DayRecord {
+    /// Create a record for a day with no recorded events yet.
+    #[must_use]
+    pub fn new(day: u8) -> Self {
+        Self {
+            day,
+            status: DayStatus::Completed,
+            parse_duration: None,
+            parts: Vec::new(),
+        }
+    }
+
+    /// Create a record for a day that was skipped.
+    #[must_use]
+    pub fn skipped(day: u8, reason: impl Into<String>) -> Self {
+        Self {
+            status: DayStatus::Skipped(reason.into()),
+            ..Self::new(day)
+        }
+    }
+
+    /// Get the recorded answer of a part.
+    #[must_use]
+    pub fn answer(&self, part: SolutionPart) -> Option<&str> {
+        self.parts
+            .iter()
+            .find(|record| record.part == part)
+            .map(|record| record.answer.as_str())
+    }
+
+    /// Get the total of the parse and part durations recorded.
+    #[must_use]
+    pub fn total_duration(&self) -> Duration {
+        self.parts
+            .iter()
+            .filter_map(|record| record.duration)
+            .chain(self.parse_duration)
+            .sum()
+    }
+}
+
+/// An output handler that records answers and durations of a day while
+/// forwarding events to another handler.
+///
+/// The solution is expected to run timed so durations can be recorded. If
+/// `forward_timed` is false, timed events are forwarded to the inner handler
+/// as their untimed counterparts.
+pub struct RecordingHandler<'a> {
+    /// The handler to forward events to.
+    inner: &'a mut dyn OutputHandler,
+    /// Whether to forward timed events as timed.
+    forward_timed: bool,
+    /// The record being built.
+    record: DayRecord,
+}
+
+impl<'a> RecordingHandler<'a> {
+    /// Construct a recording handler for a day.
+    #[must_use]
+    pub fn new(
+        day: u8,
+        inner: &'a mut dyn OutputHandler,
+        forward_timed: bool,
+    ) -> Self {
+        Self {
+            inner,
+            forward_timed,
+            record: DayRecord::new(day),
+        }
+    }
+
+    /// Finish recording, returning the built record.
+    #[must_use]
+    pub fn finish(self) -> DayRecord {
+        self.record
+    }
+
+    /// Record the answer of a part.
+    fn record_part(
+        &mut self,
+        part: SolutionPart,
+        output: &dyn Display,
+        duration: Option<Duration>,
+    ) {
+        self.record.parts.push(PartRecord {
+            part,
+            answer: output.to_string(),
+            duration,
+        });
+    }
+}
+
+impl OutputHandler for RecordingHandler<'_> {
+    fn solution_name(&mut self, name: &str) {
+        self.inner.solution_name(name);
+    }
+
+    fn parse_start(&mut self) {
+        self.inner.parse_start();
+    }
+
+    fn parse_end(&mut self) {
+        self.inner.parse_end();
+    }
+
+    fn parse_end_timed(&mut self, duration: Duration) {
+        self.record.parse_duration = Some(duration);
+        if self.forward_timed {
+            self.inner.parse_end_timed(duration);
+        } else {
+            self.inner.parse_end();
+        }
+    }
+
+    fn part_start(&mut self, part: SolutionPart) {
+        self.inner.part_start(part);
+    }
+
+    fn part_output(&mut self, part: SolutionPart, output: &dyn Display) {
+        self.record_part(part, output, None);
+        self.inner.part_output(part, output);
+    }
+
+    fn part_output_timed(
+        &mut self,
+        part: SolutionPart,
+        output: &dyn Display,
+        duration: Duration,
+    ) {
+        self.record_part(part, output, Some(duration));
+        if self.forward_timed {
+            self.inner.part_output_timed(part, output, duration);
+        } else {
+            self.inner.part_output(part, output);
+        }
+    }
+}
+
+/// Format a table summarizing answers and durations of day records.
+///
+/// Days that were skipped or failed show their reason in place of answers.
+/// The table ends with the total durations across all days.
+#[must_use]
+pub fn format_summary(records: &[DayRecord]) -> String {
+    const HEADERS: [&str; 4] = ["Day", "Part 1", "Part 2", "Time"];
+
+    // build rows of cells first to measure column widths
+    let mut rows: Vec<[String; 4]> = Vec::with_capacity(records.len());
+    for record in records {
+        let day = record.day.to_string();
+        let row = match &record.status {
+            DayStatus::Completed => [
+                day,
+                record
+                    .answer(SolutionPart::Part1)
+                    .unwrap_or("-")
+                    .to_string(),
+                record
+                    .answer(SolutionPart::Part2)
+                    .unwrap_or("-")
+                    .to_string(),
+                format_duration(record.total_duration()),
+            ],
+            DayStatus::Skipped(reason) => {
+                [day, format!("skipped: {reason}"), String::new(), "-".into()]
+            }
+            DayStatus::Failed(error) => {
+                [day, format!("failed: {error}"), String::new(), "-".into()]
+            }
+        };
+        rows.push(row);
+    }
+
+    let mut widths = HEADERS.map(str::len);
+    for row in &rows {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.chars().count());
+        }
+    }
+
+    let mut table = String::new();
+    let mut push_row = |cells: [&str; 4]| {
+        let [day, part1, part2, time] = cells;
+        let [day_w, part1_w, part2_w, time_w] = widths;
+        let line = format!(
+            "{day:>day_w$} | {part1:<part1_w$} | {part2:<part2_w$} | {time:>time_w$}"
+        );
+        // writing to a string can't fail
+        let _ = writeln!(table, "{}", line.trim_end());
+    };
+
+    push_row(HEADERS);
+    for row in &rows {
+        push_row(row.each_ref().map(String::as_str));
+    }
+
+    let parse_total: Duration = records
+        .iter()
+        .filter_map(|record| record.parse_duration)
+        .sum();
+    let parts_total: Duration = records
+        .iter()
+        .flat_map(|record| &record.parts)
+        .filter_map(|part| part.duration)
+        .sum();
+    let _ = writeln!(
+        table,
+        "Total time: {} (parse {}, parts {})",
+        format_duration(parse_total + parts_total),
+        format_duration(parse_total),
+        format_duration(parts_total)
+    );
+
+    table
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_framework::testing::NullHandler;
+
+    use super::*;
+
+    #[test]
+    fn recording_handler_records_answers_and_durations() {
+        let mut inner = NullHandler;
+        let mut handler = RecordingHandler::new(4, &mut inner, false);
+        handler.parse_end_timed(Duration::from_micros(5));
+        handler.part_output_timed(
+            SolutionPart::Part1,
+            &13,
+            Duration::from_micros(10),
+        );
+        handler.part_output(SolutionPart::Part2, &"43");
+
+        let record = handler.finish();
+        assert_eq!(record.day, 4);
+        assert_eq!(record.status, DayStatus::Completed);
+        assert_eq!(record.answer(SolutionPart::Part1), Some("13"));
+        assert_eq!(record.answer(SolutionPart::Part2), Some("43"));
+        assert_eq!(record.total_duration(), Duration::from_micros(15));
+    }
+
+    #[test]
+    fn format_summary_aligns_columns_and_totals() {
+        let completed = DayRecord {
+            day: 1,
+            status: DayStatus::Completed,
+            parse_duration: Some(Duration::from_micros(100)),
+            parts: vec![
+                PartRecord {
+                    part: SolutionPart::Part1,
+                    answer: "3".into(),
+                    duration: Some(Duration::from_micros(200)),
+                },
+                PartRecord {
+                    part: SolutionPart::Part2,
+                    answer: "6".into(),
+                    duration: Some(Duration::from_micros(300)),
+                },
+            ],
+        };
+        let skipped = DayRecord::skipped(12, "missing input");
+
+        let table = format_summary(&[completed, skipped]);
+        let expected = "\
+Day | Part 1                 | Part 2 |       Time
+  1 | 3                      | 6      | 600.000 µs
+ 12 | skipped: missing input |        |          -
+Total time: 600.000 µs (parse 100.000 µs, parts 500.000 µs)
+";
+        assert_eq!(table, expected);
+    }
+}
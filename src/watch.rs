@@ -0,0 +1,160 @@
+//! Polling of watched files, and diffing of answers between runs.
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+
+use aoc_framework::SolutionPart;
+
+use crate::summary::DayRecord;
+
+/// The modification times of watched files, for detecting changes by
+/// polling.
+///
+/// Missing or unreadable files have no time, so a file being created or
+/// removed is also a change.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Snapshot(BTreeMap<PathBuf, Option<SystemTime>>);
+
+impl Snapshot {
+    /// Take a snapshot of the modification times of files.
+    #[must_use]
+    pub fn take(paths: &[PathBuf]) -> Self {
+        Self(
+            paths
+                .iter()
+                .map(|path| {
+                    let modified = fs::metadata(path)
+                        .and_then(|metadata| metadata.modified())
+                        .ok();
+                    (path.clone(), modified)
+                })
+                .collect(),
+        )
+    }
+
+    /// Get the paths of files changed since an earlier snapshot, including
+    /// files not in the earlier snapshot.
+    pub fn changed_since<'a>(
+        &'a self,
+        earlier: &'a Self,
+    ) -> impl Iterator<Item = &'a Path> {
+        self.0
+            .iter()
+            .filter(|(path, modified)| earlier.0.get(*path) != Some(modified))
+            .map(|(path, _)| path.as_path())
+    }
+}
+
+/// Get the example input files in a directory, sorted by path.
+///
+/// A missing directory has no examples.
+#[must_use]
+pub fn example_inputs(dir: &Path) -> Vec<PathBuf> {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return Vec::new();
+    };
+    let mut paths: Vec<PathBuf> = entries
+        .filter_map(|entry| Some(entry.ok()?.path()))
+        .filter(|path| path.extension().is_some_and(|ext| ext == "txt"))
+        .collect();
+    paths.sort();
+    paths
+}
+
+/// Describe how the answers of a run changed from a previous run of the same
+/// input, one line per part answered in either run.
+#[must_use]
+pub fn diff_answers(previous: &DayRecord, current: &DayRecord) -> Vec<String> {
+    [SolutionPart::Part1, SolutionPart::Part2]
+        .into_iter()
+        .filter_map(|part| {
+            let name = part.default_name();
+            let line = match (previous.answer(part), current.answer(part)) {
+                (None, None) => return None,
+                (Some(before), Some(after)) if before == after => {
+                    format!("{name}: {after} (unchanged)")
+                }
+                (Some(before), Some(after)) => {
+                    format!("{name}: {before} -> {after}")
+                }
+                (None, Some(after)) => format!("{name}: {after} (new)"),
+                (Some(before), None) => {
+                    format!("{name}: {before} -> (no answer)")
+                }
+            };
+            Some(line)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+    use crate::summary::PartRecord;
+
+    fn record(answers: &[(SolutionPart, &str)]) -> DayRecord {
+        let mut record = DayRecord::new(1);
+        record.parts = answers
+            .iter()
+            .map(|&(part, answer)| PartRecord {
+                part,
+                answer: answer.into(),
+                duration: None,
+            })
+            .collect();
+        record
+    }
+
+    #[test]
+    fn diff_describes_changed_answers() {
+        let previous = record(&[(SolutionPart::Part1, "10")]);
+        let current =
+            record(&[(SolutionPart::Part1, "12"), (SolutionPart::Part2, "7")]);
+        assert_eq!(
+            diff_answers(&previous, &current),
+            ["Part 1: 10 -> 12", "Part 2: 7 (new)"]
+        );
+        assert_eq!(
+            diff_answers(&current, &current),
+            ["Part 1: 12 (unchanged)", "Part 2: 7 (unchanged)"]
+        );
+        assert_eq!(
+            diff_answers(&current, &record(&[])),
+            ["Part 1: 12 -> (no answer)", "Part 2: 7 -> (no answer)"]
+        );
+    }
+
+    #[test]
+    fn snapshot_detects_created_and_modified_files() {
+        let path = std::env::temp_dir()
+            .join(format!("aoc-2025-watch-{}.txt", std::process::id()));
+        let _ = fs::remove_file(&path);
+        let paths = [path.clone()];
+
+        let missing = Snapshot::take(&paths);
+        assert_eq!(missing.changed_since(&missing).count(), 0);
+        assert_eq!(missing.changed_since(&Snapshot::default()).count(), 1);
+
+        fs::write(&path, "1").expect("failed to write file");
+        let created = Snapshot::take(&paths);
+        assert_eq!(
+            created.changed_since(&missing).collect::<Vec<_>>(),
+            [path.as_path()]
+        );
+
+        let file = fs::File::options()
+            .write(true)
+            .open(&path)
+            .expect("failed to open file");
+        file.set_modified(SystemTime::now() + Duration::from_mins(1))
+            .expect("failed to set modified time");
+        let modified = Snapshot::take(&paths);
+        assert_eq!(modified.changed_since(&created).count(), 1);
+
+        fs::remove_file(&path).expect("failed to remove file");
+    }
+}